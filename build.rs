@@ -12,6 +12,22 @@ fn main() {
     if probe.probe_type("::std::ops::RangeInclusive<u64>") {
         enable_cfg("inclusive_range");
     }
+
+    if probe.probe_type("::std::sync::atomic::AtomicU8") {
+        enable_cfg("atomic_u8");
+    }
+
+    if probe.probe_expression("::std::thread::scope(|_| ())") {
+        enable_cfg("thread_scope");
+    }
+
+    if probe.probe_expression("{ let mut a = [0u8]; a.rotate_left(0) }") {
+        enable_cfg("slice_rotate");
+    }
+
+    if probe.probe_expression("{ let mut a = [0u8]; a.copy_within(0 .. 0, 0) }") {
+        enable_cfg("slice_copy_within");
+    }
 }
 
 /// Enables `--cfg feature` for the current build.
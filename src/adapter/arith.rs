@@ -0,0 +1,206 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+
+use traits::get_masked_block;
+
+use std::cmp;
+use std::sync::atomic::{self, AtomicUsize};
+
+/// The result of [`BitsExt::bit_add`](../trait.BitsExt.html#method.bit_add).
+///
+/// The resulting bit vector adapter adds the two underlying
+/// bit-vector-likes as unsigned integers, least-significant bit first,
+/// modulo two to the length of the result.
+///
+/// The adapter remembers the carry into the block read last, so reading
+/// the blocks in order, as `to_bit_vec`, `==` and `count_ones` do, takes
+/// constant time per block. Reading a block out of order may scan down
+/// through every lower block pair whose sum is all ones, so in the worst
+/// case it is linear in the number of blocks.
+#[derive(Clone, Debug)]
+pub struct BitAdd<T, U>(BitArithOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitAdd<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitAdd(BitArithOp::new(bits1, bits2))
+    }
+}
+
+/// The result of [`BitsExt::bit_sub`](../trait.BitsExt.html#method.bit_sub).
+///
+/// The resulting bit vector adapter subtracts the second underlying
+/// bit-vector-like from the first as unsigned integers, least-significant
+/// bit first, modulo two to the length of the result.
+///
+/// The adapter remembers the borrow from the block read last, so reading
+/// the blocks in order, as `to_bit_vec`, `==` and `count_ones` do, takes
+/// constant time per block. Reading a block out of order may scan down
+/// through every lower pair of equal blocks, so in the worst case it is
+/// linear in the number of blocks.
+#[derive(Clone, Debug)]
+pub struct BitSub<T, U>(BitArithOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitSub<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitSub(BitArithOp::new(bits1, bits2))
+    }
+}
+
+/// The operands to an arithmetic operation, along with the length of the
+/// result (the minimum length of the operands) and the carry or borrow into
+/// the block read most recently.
+#[derive(Debug)]
+struct BitArithOp<T, U> {
+    op1:   T,
+    op2:   U,
+    len:   u64,
+    // The position of the last block read, shifted left by one, with the
+    // carry or borrow into it in the low bit. This is atomic rather than a
+    // `Cell` so that the adapters remain `Sync`; a stale read only costs a
+    // rescan.
+    cache: AtomicUsize,
+}
+
+impl<T: Clone, U: Clone> Clone for BitArithOp<T, U> {
+    fn clone(&self) -> Self {
+        BitArithOp {
+            op1:   self.op1.clone(),
+            op2:   self.op2.clone(),
+            len:   self.len,
+            cache: AtomicUsize::new(self.cache.load(atomic::Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitArithOp<T, U> {
+    fn new(op1: T, op2: U) -> Self {
+        let len = cmp::min(op1.bit_len(), op2.bit_len());
+        BitArithOp { op1, op2, len, cache: AtomicUsize::new(0) }
+    }
+
+    fn blocks(&self, position: usize) -> (T::Block, T::Block) {
+        (self.op1.get_raw_block(position), self.op2.get_raw_block(position))
+    }
+
+    // Whether adding blocks `.. position` carries into block `position`.
+    fn carry_into(&self, position: usize) -> bool {
+        self.incoming(position, |a, b, carry| {
+            let sum = a.wrapping_add(b);
+            sum < a || (carry && sum == !T::Block::zero())
+        })
+    }
+
+    // Whether subtracting blocks `.. position` borrows from block
+    // `position`.
+    fn borrow_from(&self, position: usize) -> bool {
+        self.incoming(position, |a, b, borrow| a < b || (borrow && a == b))
+    }
+
+    // Finds the carry or borrow into block `position`, where `out` gives the
+    // carry or borrow out of a block pair given the one into it. Reading the
+    // block just above the cached one takes one step. Otherwise we scan down
+    // until finding a block pair whose outgoing carry doesn't depend on the
+    // incoming one, or until reaching the cached block from above.
+    fn incoming<F>(&self, position: usize, out: F) -> bool
+        where F: Fn(T::Block, T::Block, bool) -> bool {
+
+        let cached  = self.cache.load(atomic::Ordering::Relaxed);
+        let last    = cached >> 1;
+        let last_in = cached & 1 == 1;
+
+        let result = if position == last {
+            last_in
+        } else if position == last + 1 {
+            let (a, b) = self.blocks(last);
+            out(a, b, last_in)
+        } else {
+            // If every block pair in `floor .. position` passes along what
+            // comes into it, the result is what comes into `floor`.
+            let (floor, mut result) = if last < position {(last, last_in)} else {(0, false)};
+
+            for i in (floor .. position).rev() {
+                let (a, b) = self.blocks(i);
+                let low    = out(a, b, false);
+                if low == out(a, b, true) {
+                    result = low;
+                    break;
+                }
+            }
+
+            result
+        };
+
+        self.cache.store(position << 1 | result as usize, atomic::Ordering::Relaxed);
+        result
+    }
+}
+
+macro_rules! impl_bits_arith_op {
+    ( $target:ident, $block_fn:ident ) => {
+        impl<T, U> Bits for $target<T, U>
+            where T: Bits,
+                  U: Bits<Block = T::Block>
+        {
+            type Block = T::Block;
+
+            fn bit_len(&self) -> u64 {
+                self.0.len
+            }
+
+            fn get_block(&self, position: usize) -> Self::Block {
+                assert!( position < self.block_len(),
+                         format!("{}::get_block: out of bounds", stringify!($target)) );
+                get_masked_block(self, position)
+            }
+
+            fn get_raw_block(&self, position: usize) -> Self::Block {
+                $block_fn(&self.0, position)
+            }
+        }
+
+        impl_index_from_bits! {
+            impl[T: Bits, U: Bits<Block = T::Block>] Index<u64> for $target<T, U>;
+        }
+
+        impl_bit_ops_from_bits! {
+            impl[T: Bits, U: Bits<Block = T::Block>] BitOps for $target<T, U>;
+        }
+
+        impl_bit_sliceable_adapter! {
+            impl[T: Bits, U: Bits<Block = T::Block>] BitSliceable for $target<T, U>;
+            impl['a, T: Bits, U: Bits<Block = T::Block>] BitSliceable for &'a $target<T, U>;
+        }
+
+        impl<T, U, V> PartialEq<V> for $target<T, U>
+            where T: Bits,
+                  U: Bits<Block = T::Block>,
+                  V: Bits<Block = T::Block> {
+
+            fn eq(&self, other: &V) -> bool {
+                BlockIter::new(self) == BlockIter::new(other)
+            }
+        }
+    };
+}
+
+fn add_block<T, U>(ops: &BitArithOp<T, U>, position: usize) -> T::Block
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    let (a, b) = ops.blocks(position);
+    let carry  = if ops.carry_into(position) {T::Block::one()} else {T::Block::zero()};
+    a.wrapping_add(b).wrapping_add(carry)
+}
+
+fn sub_block<T, U>(ops: &BitArithOp<T, U>, position: usize) -> T::Block
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    let (a, b) = ops.blocks(position);
+    let borrow = if ops.borrow_from(position) {T::Block::one()} else {T::Block::zero()};
+    a.wrapping_sub(b).wrapping_sub(borrow)
+}
+
+impl_bits_arith_op!(BitAdd, add_block);
+impl_bits_arith_op!(BitSub, sub_block);
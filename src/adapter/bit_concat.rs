@@ -70,6 +70,10 @@ impl_index_from_bits! {
     impl[T: Bits, U: Bits<Block = T::Block>] Index<u64> for BitConcat<T, U>;
 }
 
+impl_bit_ops_from_bits! {
+    impl[T: Bits, U: Bits<Block = T::Block>] BitOps for BitConcat<T, U>;
+}
+
 impl_bit_sliceable_adapter! {
     impl[T: Bits, U: Bits<Block = T::Block>] BitSliceable for BitConcat<T, U>;
     impl['a, T: Bits, U: Bits<Block = T::Block>] BitSliceable for &'a BitConcat<T, U>;
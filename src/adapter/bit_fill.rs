@@ -44,6 +44,14 @@ impl<Block: BlockType> Bits for BitFill<Block> {
 }
 
 impl<Block: BlockType> BitFill<Block> {
+    /// Constructs a compact bit-vector-like of `len` copies of `value`.
+    pub fn new(value: bool, len: u64) -> Self {
+        BitFill {
+            len,
+            block: if value {!Block::zero()} else {Block::zero()},
+        }
+    }
+
     /// Constructs a compact bit-vector-like of `len` 0s.
     pub fn zeroes(len: u64) -> Self {
         BitFill {
@@ -71,6 +79,10 @@ impl_index_from_bits! {
     impl[Block: BlockType] Index<u64> for BitFill<Block>;
 }
 
+impl_bit_ops_from_bits! {
+    impl[Block: BlockType] BitOps for BitFill<Block>;
+}
+
 impl_bit_sliceable_adapter! {
     impl[Block: BlockType] BitSliceable for BitFill<Block>;
     impl['a, Block: BlockType] BitSliceable for &'a BitFill<Block>;
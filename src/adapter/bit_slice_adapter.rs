@@ -212,6 +212,10 @@ impl_index_from_bits! {
     impl[T: Bits] Index<u64> for BitSliceAdapter<T>;
 }
 
+impl_bit_ops_from_bits! {
+    impl[T: Bits] BitOps for BitSliceAdapter<T>;
+}
+
 impl<T: Bits> BitSliceable<Range<u64>> for BitSliceAdapter<T> {
     type Slice = Self;
 
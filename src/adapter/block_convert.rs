@@ -0,0 +1,94 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+use storage::Address;
+
+use traits::get_masked_block;
+
+use std::cmp;
+use std::marker::PhantomData;
+
+/// The result of
+/// [`BitsExt::with_block`](../trait.BitsExt.html#method.with_block).
+///
+/// The resulting bit vector adapter presents the bits of the underlying
+/// bit-vector-like in blocks of type `NewBlock`. Narrowing reads one
+/// source block per result block, and widening assembles each result
+/// block from several source blocks, shifting each into place.
+#[derive(Clone, Debug)]
+pub struct BlockConvert<T, NewBlock> {
+    bits:   T,
+    marker: PhantomData<NewBlock>,
+}
+
+impl<T: Bits, NewBlock: BlockType> BlockConvert<T, NewBlock> {
+    pub (crate) fn new(bits: T) -> Self {
+        BlockConvert {
+            bits,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Bits, NewBlock: BlockType> Bits for BlockConvert<T, NewBlock> {
+    type Block = NewBlock;
+
+    fn bit_len(&self) -> u64 {
+        self.bits.bit_len()
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        self.bits.get_bit(position)
+    }
+
+    fn get_block(&self, position: usize) -> NewBlock {
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> NewBlock {
+        assert!( position < self.block_len(), "BlockConvert::get_raw_block: out of bounds" );
+
+        // We move the bits in chunks that fit in a `u64` and in both block
+        // types. Since block sizes are powers of two, no chunk straddles a
+        // source block.
+        let chunk = cmp::min(64, cmp::min(T::Block::nbits(), NewBlock::nbits()));
+        let start = NewBlock::mul_nbits(position);
+        let len   = self.bits.bit_len();
+
+        let mut result = NewBlock::zero();
+        let mut shift  = 0;
+
+        while shift < NewBlock::nbits() && start + (shift as u64) < len {
+            let address = Address::new::<T::Block>(start + shift as u64);
+            let block   = self.bits.get_raw_block(address.block_index) >> address.bit_offset;
+            let piece   = block.to_u64_wrapping() & u64::low_mask(chunk);
+            result = result | NewBlock::from_u64_wrapping(piece) << shift;
+            shift += chunk;
+        }
+
+        result
+    }
+}
+
+impl_index_from_bits! {
+    impl[T: Bits, NewBlock: BlockType] Index<u64> for BlockConvert<T, NewBlock>;
+}
+
+impl_bit_sliceable_adapter! {
+    impl[T: Bits, NewBlock: BlockType] BitSliceable for BlockConvert<T, NewBlock>;
+    impl['a, T: Bits, NewBlock: BlockType] BitSliceable for &'a BlockConvert<T, NewBlock>;
+}
+
+impl_bit_ops_from_bits! {
+    impl[T: Bits, NewBlock: BlockType] BitOps for BlockConvert<T, NewBlock>;
+}
+
+impl<T, NewBlock, U> PartialEq<U> for BlockConvert<T, NewBlock>
+    where T: Bits,
+          NewBlock: BlockType,
+          U: Bits<Block = NewBlock> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
@@ -0,0 +1,284 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+
+use traits::get_masked_block;
+
+use std::cmp;
+
+/// Interleaves the bits of several bit-vector-likes, as in a Z-order
+/// (Morton) curve.
+///
+/// With `k` inputs, bit `i` of input `j` becomes bit `i * k + j` of the
+/// result. If the lengths of the inputs differ, the result interleaves
+/// only the shortest length of each; its length is `k` times that.
+///
+/// See [`BitsExt::bit_deinterleave`] for the inverse.
+///
+/// # Examples
+///
+/// ```
+/// use bv::*;
+/// use bv::adapter::bit_interleave;
+///
+/// let x: BitVec = bit_vec![true, true, false];
+/// let y: BitVec = bit_vec![false, true, true];
+///
+/// let inputs = [&x, &y];
+/// let z = bit_interleave(&inputs);
+/// assert_eq!( z, bit_vec![true, false, true, true, false, true] );
+/// assert_eq!( z.bit_deinterleave(2, 1), y );
+/// ```
+///
+/// [`BitsExt::bit_deinterleave`]: ../trait.BitsExt.html#method.bit_deinterleave
+pub fn bit_interleave<'a, T: Bits>(inputs: &'a [T]) -> BitInterleave<'a, T> {
+    BitInterleave::new(inputs)
+}
+
+/// The result of [`bit_interleave`](fn.bit_interleave.html).
+///
+/// Each block of the result is assembled from one `get_bits` per input,
+/// spread out to every `k`th bit by a logarithmic sequence of shifts and
+/// masks.
+#[derive(Debug)]
+pub struct BitInterleave<'a, T: 'a + Bits> {
+    inputs: &'a [T],
+    len:    u64,
+    spread: Spread<T::Block>,
+}
+
+impl<'a, T: 'a + Bits> Clone for BitInterleave<'a, T> {
+    fn clone(&self) -> Self {
+        BitInterleave { inputs: self.inputs, len: self.len, spread: self.spread }
+    }
+}
+
+impl<'a, T: Bits> BitInterleave<'a, T> {
+    pub (crate) fn new(inputs: &'a [T]) -> Self {
+        let min_len = inputs.iter().map(Bits::bit_len).min().unwrap_or(0);
+        BitInterleave {
+            inputs,
+            len:    min_len * inputs.len() as u64,
+            spread: Spread::new(cmp::max(inputs.len(), 1) as u64),
+        }
+    }
+}
+
+/// The result of
+/// [`BitsExt::bit_deinterleave`](../trait.BitsExt.html#method.bit_deinterleave).
+///
+/// The resulting bit vector adapter presents every `k`th bit of the
+/// underlying bit-vector-like, starting with bit `i`. Each block is
+/// gathered from the blocks that it spans, compacting every `k`th bit by
+/// a logarithmic sequence of shifts and masks.
+#[derive(Clone, Debug)]
+pub struct BitDeinterleave<T: Bits> {
+    bits:   T,
+    k:      u64,
+    start:  u64,
+    len:    u64,
+    spread: Spread<T::Block>,
+}
+
+impl<T: Bits> BitDeinterleave<T> {
+    pub (crate) fn new(bits: T, k: usize, start: usize) -> Self {
+        assert!( start < k, "BitDeinterleave::new: component out of range" );
+
+        let k     = k as u64;
+        let start = start as u64;
+        let len   = (bits.bit_len() + k - 1 - start) / k;
+
+        BitDeinterleave { bits, k, start, len, spread: Spread::new(k) }
+    }
+}
+
+// Moves the low bits of a block to every `k`th bit and back, in the manner
+// of the Morton-code bit tricks. With `m` the number of every-`k`th
+// positions in a block and `s` a power of two, `masks[j]` has bit
+// `(i / s) * s * k + i % s` set for each `i < m`, where `s = 2^j`. That is,
+// it holds groups of `s` adjacent bits, `s * k` apart. Going from `2 * s`
+// to `s`, the upper half of each group moves up by `s * (k - 1)`.
+#[derive(Clone, Copy, Debug)]
+struct Spread<Block> {
+    k:     u64,
+    steps: usize,
+    masks: [Block; 8],
+}
+
+impl<Block: BlockType> Spread<Block> {
+    fn new(k: u64) -> Self {
+        let nbits = Block::nbits() as u64;
+        let m     = (nbits - 1) / k + 1;
+
+        let mut masks = [Block::zero(); 8];
+        let mut steps = 0;
+        let mut s     = 1;
+
+        loop {
+            for i in 0 .. m {
+                let position = (i / s) * s * k + i % s;
+                masks[steps] = masks[steps].with_bit(position as usize, true);
+            }
+            if s >= m { break; }
+            s *= 2;
+            steps += 1;
+        }
+
+        Spread { k, steps, masks }
+    }
+
+    // The block with every `k`th bit set, starting with bit 0.
+    fn every_kth(&self) -> Block {
+        self.masks[0]
+    }
+
+    // Moves bit `i` of `block` to bit `i * k`, for the bits that fit.
+    fn spread(&self, block: Block) -> Block {
+        let mut result = block & self.masks[self.steps];
+        for j in (0 .. self.steps).rev() {
+            let shift = (1 << j) * (self.k - 1) as usize;
+            result = (result | result << shift) & self.masks[j];
+        }
+        result
+    }
+
+    // Moves bit `i * k` of `block` to bit `i`, discarding the other bits.
+    fn compact(&self, block: Block) -> Block {
+        let mut result = block & self.masks[0];
+        for j in 0 .. self.steps {
+            let shift = (1 << j) * (self.k - 1) as usize;
+            result = (result | result >> shift) & self.masks[j + 1];
+        }
+        result
+    }
+}
+
+impl<'a, T: Bits> Bits for BitInterleave<'a, T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!( position < self.len, "BitInterleave::get_bit: out of bounds" );
+        let k = self.inputs.len() as u64;
+        self.inputs[(position % k) as usize].get_bit(position / k)
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitInterleave::get_block: out of bounds" );
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitInterleave::get_raw_block: out of bounds" );
+
+        let k      = self.inputs.len() as u64;
+        let start  = Self::Block::mul_nbits(position);
+        let limit  = start + Self::Block::block_bits(self.len, position) as u64;
+
+        let mut result = Self::Block::zero();
+
+        // Input `j` supplies its bits `first .. end`, which land at every
+        // `k`th bit of this block starting from `offset`.
+        for (j, input) in self.inputs.iter().enumerate() {
+            let j     = j as u64;
+            let first = (start + k - 1 - j) / k;
+            let end   = (limit + k - 1 - j) / k;
+            if end <= first { continue; }
+
+            let offset = (first * k + j - start) as usize;
+            let bits   = input.get_bits(first, (end - first) as usize);
+            result = result | self.spread.spread(bits) << offset;
+        }
+
+        result
+    }
+}
+
+impl<T: Bits> Bits for BitDeinterleave<T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!( position < self.len, "BitDeinterleave::get_bit: out of bounds" );
+        self.bits.get_bit(position * self.k + self.start)
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitDeinterleave::get_block: out of bounds" );
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitDeinterleave::get_raw_block: out of bounds" );
+
+        let nbits  = Self::Block::nbits() as u64;
+        let count  = Self::Block::block_bits(self.len, position) as u64;
+        let spread = self.spread.every_kth();
+
+        // The wanted bits of the source lie at every `k`th position of the
+        // window `base .. base + span`, which we read a block at a time.
+        let base   = Self::Block::mul_nbits(position) * self.k + self.start;
+        let span   = (count - 1) * self.k + 1;
+
+        let mut result = Self::Block::zero();
+        let mut filled = 0;
+        let mut chunk  = 0;
+
+        while chunk < span {
+            let chunk_len = cmp::min(nbits, span - chunk);
+            let offset    = ((self.k - chunk % self.k) % self.k) as usize;
+            let bits      = self.bits.get_bits(base + chunk, chunk_len as usize);
+
+            if (offset as u64) < chunk_len {
+                let mask = (spread << offset) & Self::Block::low_mask(chunk_len as usize);
+                result = result | self.spread.compact((bits & mask) >> offset) << filled;
+                filled += mask.count_ones();
+            }
+
+            chunk += nbits;
+        }
+
+        result
+    }
+}
+
+impl_index_from_bits! {
+    impl['a, T: Bits] Index<u64> for BitInterleave<'a, T>;
+    impl[T: Bits] Index<u64> for BitDeinterleave<T>;
+}
+
+impl_bit_ops_from_bits! {
+    impl['a, T: Bits] BitOps for BitInterleave<'a, T>;
+    impl[T: Bits] BitOps for BitDeinterleave<T>;
+}
+
+impl_bit_sliceable_adapter! {
+    impl['a, T: Bits] BitSliceable for BitInterleave<'a, T>;
+    impl['a, 'b, T: Bits] BitSliceable for &'b BitInterleave<'a, T>;
+    impl[T: Bits] BitSliceable for BitDeinterleave<T>;
+    impl['a, T: Bits] BitSliceable for &'a BitDeinterleave<T>;
+}
+
+impl<'a, T, U> PartialEq<U> for BitInterleave<'a, T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
+
+impl<T, U> PartialEq<U> for BitDeinterleave<T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
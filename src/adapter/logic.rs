@@ -58,6 +58,74 @@ impl<T: Bits, U: Bits<Block = T::Block>> BitXor<T, U> {
     }
 }
 
+/// The result of
+/// [`BitsExt::bit_and_not`](../trait.BitsExt.html#method.bit_and_not).
+///
+/// The resulting bit vector adapter *and*s the bits of the first underlying
+/// bit-vector-like with the complement of the bits of the second.
+#[derive(Clone, Debug)]
+pub struct BitAndNot<T, U>(BitBinOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitAndNot<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitAndNot(BitBinOp::new(bits1, bits2))
+    }
+}
+
+/// The result of [`BitsExt::bit_nand`](../trait.BitsExt.html#method.bit_nand).
+///
+/// The resulting bit vector adapter *nand*s the bits of the two underlying
+/// bit-vector-likes.
+#[derive(Clone, Debug)]
+pub struct BitNand<T, U>(BitBinOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitNand<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitNand(BitBinOp::new(bits1, bits2))
+    }
+}
+
+/// The result of [`BitsExt::bit_nor`](../trait.BitsExt.html#method.bit_nor).
+///
+/// The resulting bit vector adapter *nor*s the bits of the two underlying
+/// bit-vector-likes.
+#[derive(Clone, Debug)]
+pub struct BitNor<T, U>(BitBinOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitNor<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitNor(BitBinOp::new(bits1, bits2))
+    }
+}
+
+/// The result of [`BitsExt::bit_xnor`](../trait.BitsExt.html#method.bit_xnor).
+///
+/// The resulting bit vector adapter *xnor*s the bits of the two underlying
+/// bit-vector-likes.
+#[derive(Clone, Debug)]
+pub struct BitXnor<T, U>(BitBinOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitXnor<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitXnor(BitBinOp::new(bits1, bits2))
+    }
+}
+
+/// The result of
+/// [`BitsExt::bit_implies`](../trait.BitsExt.html#method.bit_implies).
+///
+/// The resulting bit vector adapter computes the implication from each bit of
+/// the first underlying bit-vector-like to the corresponding bit of the
+/// second.
+#[derive(Clone, Debug)]
+pub struct BitImplies<T, U>(BitBinOp<T, U>);
+
+impl<T: Bits, U: Bits<Block = T::Block>> BitImplies<T, U> {
+    pub (crate) fn new(bits1: T, bits2: U) -> Self {
+        BitImplies(BitBinOp::new(bits1, bits2))
+    }
+}
+
 /// The result of [`BitsExt::bit_zip`](../trait.BitsExt.html#method.bit_zip).
 #[derive(Clone, Debug)]
 pub struct BitZip<T, U, F> {
@@ -74,6 +142,44 @@ impl<T: Bits, U: Bits<Block = T::Block>, F> BitZip<T, U, F> {
     }
 }
 
+/// The result of [`BitsExt::bit_zip3`](../trait.BitsExt.html#method.bit_zip3).
+///
+/// The resulting bit vector adapter zips a function over the blocks of the
+/// three underlying bit-vector-likes, fetching one block of each per block
+/// of the result.
+#[derive(Clone, Debug)]
+pub struct BitZip3<T, U, V, F> {
+    op1: T,
+    op2: U,
+    op3: V,
+    len: u64,
+    fun: F,
+}
+
+impl<T, U, V, F> BitZip3<T, U, V, F>
+    where T: Bits,
+          U: Bits<Block = T::Block>,
+          V: Bits<Block = T::Block> {
+
+    pub (crate) fn new(bits1: T, bits2: U, bits3: V, fun: F) -> Self {
+        let len = cmp::min(bits1.bit_len(), cmp::min(bits2.bit_len(), bits3.bit_len()));
+        BitZip3 {
+            op1: bits1,
+            op2: bits2,
+            op3: bits3,
+            len,
+            fun,
+        }
+    }
+}
+
+/// The result of
+/// [`BitsExt::bit_select`](../trait.BitsExt.html#method.bit_select).
+pub type BitSelect<T, U, V> = BitZip3<T, U, V, SelectFn<<T as Bits>::Block>>;
+
+/// The block function of a [`BitSelect`](type.BitSelect.html).
+pub type SelectFn<Block> = fn(Block, Block, Block) -> Block;
+
 /// Used to store the two operands to a bitwise logical operation on
 /// `Bits`es, along with the length of the result (min the length of
 /// the operands). (Note that `len` is derivable from `op1` and `op2`,
@@ -120,7 +226,7 @@ impl<T: Bits> Bits for BitNot<T> {
     }
 
     fn get_block(&self, position: usize) -> Self::Block {
-        !self.0.get_block(position)
+        get_masked_block(self, position)
     }
 
     fn get_raw_block(&self, position: usize) -> Self::Block {
@@ -132,6 +238,10 @@ impl_index_from_bits! {
     impl[T: Bits] Index<u64> for BitNot<T>;
 }
 
+impl_bit_ops_from_bits! {
+    impl[T: Bits] BitOps for BitNot<T>;
+}
+
 impl<R, T> BitSliceable<R> for BitNot<T>
     where T: BitSliceable<R> {
 
@@ -155,8 +265,10 @@ impl<T, U> PartialEq<U> for BitNot<T>
     }
 }
 
+// Implements `Bits` and friends for a binary adapter, given its operation as
+// an expression in `$a` and `$b` that works on both `bool`s and blocks.
 macro_rules! impl_bits_bin_op {
-    ( $target:ident as $block_op:tt $bool_op:tt ) => {
+    ( $target:ident as |$a:ident, $b:ident| $op:expr ) => {
         impl<T, U> Bits for $target<T, U>
             where T: Bits,
                   U: Bits<Block = T::Block>
@@ -170,7 +282,9 @@ macro_rules! impl_bits_bin_op {
             fn get_bit(&self, position: u64) -> bool {
                 assert!( position < self.bit_len(),
                          format!("{}::get_bit: out of bounds", stringify!($target)) );
-                self.0.bit1(position) $bool_op self.0.bit2(position)
+                let $a = self.0.bit1(position);
+                let $b = self.0.bit2(position);
+                $op
             }
 
             fn get_block(&self, position: usize) -> Self::Block {
@@ -180,7 +294,9 @@ macro_rules! impl_bits_bin_op {
             }
 
             fn get_raw_block(&self, position: usize) -> Self::Block {
-                self.0.block1(position) $block_op self.0.block2(position)
+                let $a = self.0.block1(position);
+                let $b = self.0.block2(position);
+                $op
             }
         }
 
@@ -188,6 +304,10 @@ macro_rules! impl_bits_bin_op {
             impl[T: Bits, U: Bits<Block = T::Block>] Index<u64> for $target<T, U>;
         }
 
+        impl_bit_ops_from_bits! {
+            impl[T: Bits, U: Bits<Block = T::Block>] BitOps for $target<T, U>;
+        }
+
         impl<Block, R, T, U> BitSliceable<R> for $target<T, U>
             where Block: BlockType,
                   R: Clone,
@@ -218,9 +338,14 @@ macro_rules! impl_bits_bin_op {
     };
 }
 
-impl_bits_bin_op!(BitAnd as & &&);
-impl_bits_bin_op!(BitOr  as | ||);
-impl_bits_bin_op!(BitXor as ^ ^);
+impl_bits_bin_op!(BitAnd     as |a, b| a & b);
+impl_bits_bin_op!(BitOr      as |a, b| a | b);
+impl_bits_bin_op!(BitXor     as |a, b| a ^ b);
+impl_bits_bin_op!(BitAndNot  as |a, b| a & !b);
+impl_bits_bin_op!(BitNand    as |a, b| !(a & b));
+impl_bits_bin_op!(BitNor     as |a, b| !(a | b));
+impl_bits_bin_op!(BitXnor    as |a, b| !(a ^ b));
+impl_bits_bin_op!(BitImplies as |a, b| !a | b);
 
 impl<T, U, F> Bits for BitZip<T, U, F>
     where T: Bits,
@@ -248,6 +373,12 @@ impl_index_from_bits! {
         Index<u64> for BitZip<T, U, F>;
 }
 
+impl_bit_ops_from_bits! {
+    impl[T: Bits, U: Bits<Block = T::Block>,
+         F: Fn(T::Block, T::Block) -> T::Block]
+        BitOps for BitZip<T, U, F>;
+}
+
 impl<Block, R, T, U, F> BitSliceable<R> for BitZip<T, U, F>
     where Block: BlockType,
           R: Clone,
@@ -283,3 +414,74 @@ impl<T, U, F, V> PartialEq<V> for BitZip<T, U, F>
     }
 }
 
+
+impl<T, U, V, F> Bits for BitZip3<T, U, V, F>
+    where T: Bits,
+          U: Bits<Block = T::Block>,
+          V: Bits<Block = T::Block>,
+          F: Fn(T::Block, T::Block, T::Block) -> T::Block {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitZip3::get_block: out of bounds" );
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        (self.fun)(self.op1.get_raw_block(position),
+                   self.op2.get_raw_block(position),
+                   self.op3.get_raw_block(position))
+    }
+}
+
+impl_index_from_bits! {
+    impl[T: Bits, U: Bits<Block = T::Block>, V: Bits<Block = T::Block>,
+         F: Fn(T::Block, T::Block, T::Block) -> T::Block]
+        Index<u64> for BitZip3<T, U, V, F>;
+}
+
+impl_bit_ops_from_bits! {
+    impl[T: Bits, U: Bits<Block = T::Block>, V: Bits<Block = T::Block>,
+         F: Fn(T::Block, T::Block, T::Block) -> T::Block]
+        BitOps for BitZip3<T, U, V, F>;
+}
+
+impl<Block, R, T, U, V, F> BitSliceable<R> for BitZip3<T, U, V, F>
+    where Block: BlockType,
+          R: Clone,
+          T: BitSliceable<R, Block = Block>,
+          U: BitSliceable<R, Block = Block>,
+          V: BitSliceable<R, Block = Block>,
+          F: Fn(Block, Block, Block) -> Block {
+
+    type Slice = BitZip3<T::Slice, U::Slice, V::Slice, F>;
+
+    fn bit_slice(self, range: R) -> Self::Slice {
+        BitZip3::new(self.op1.bit_slice(range.clone()),
+                     self.op2.bit_slice(range.clone()),
+                     self.op3.bit_slice(range),
+                     self.fun)
+    }
+}
+
+impl_bit_sliceable_adapter! {
+    impl['a, T: Bits, U: Bits<Block = T::Block>, V: Bits<Block = T::Block>,
+         F: Fn(T::Block, T::Block, T::Block) -> T::Block]
+        BitSliceable for &'a BitZip3<T, U, V, F>;
+}
+
+impl<T, U, V, F, W> PartialEq<W> for BitZip3<T, U, V, F>
+    where T: Bits,
+          U: Bits<Block = T::Block>,
+          V: Bits<Block = T::Block>,
+          W: Bits<Block = T::Block>,
+          F: Fn(T::Block, T::Block, T::Block) -> T::Block {
+
+    fn eq(&self, other: &W) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
@@ -12,7 +12,9 @@ mod bit_slice_adapter;
 pub use self::bit_slice_adapter::BitSliceAdapter;
 
 mod logic;
-pub use self::logic::{BitNot, BitAnd, BitOr, BitXor, BitZip};
+pub use self::logic::{BitNot, BitAnd, BitOr, BitXor, BitZip, BitZip3};
+pub use self::logic::{BitAndNot, BitNand, BitNor, BitXnor, BitImplies};
+pub use self::logic::{BitSelect, SelectFn};
 
 mod bit_fill;
 pub use self::bit_fill::BitFill;
@@ -20,12 +22,32 @@ pub use self::bit_fill::BitFill;
 mod bit_concat;
 pub use self::bit_concat::BitConcat;
 
+mod shift;
+pub use self::shift::{BitShl, BitShr};
+
+mod reverse;
+pub use self::reverse::BitReverse;
+
+mod arith;
+pub use self::arith::{BitAdd, BitSub};
+
+mod interleave;
+pub use self::interleave::{bit_interleave, BitInterleave, BitDeinterleave};
+
+mod block_convert;
+pub use self::block_convert::BlockConvert;
+
+mod reduce;
+pub use self::reduce::BitReduce;
+
 mod bool_adapter;
 pub use self::bool_adapter::BoolAdapter;
 
 #[cfg(test)]
 mod test {
-    use {Bits, BitsExt, BitsMut, BitVec, BitSliceable};
+    use {Bits, BitsExt, BitsMut, BitsMutExt, BitVec, BitSlice, BitSliceable, BlockType};
+    use LengthPolicy;
+    use iter::BlockIter;
     use super::BitSliceAdapter;
 
     fn assert_0001<T: Bits>(bits: &T) {
@@ -131,10 +153,270 @@ mod test {
         assert_eq!( bv, bit_vec![true, true, true, false] );
     }
 
+    #[test]
+    fn shifts() {
+        let bv: BitVec<u8> = bit_vec![true, false, true, true, false,
+                                      false, true, false, true, true];
+
+        assert_eq!( bv.bit_shl(3), bit_vec![false, false, false, true, false,
+                                            true, true, false, false, true] );
+        assert_eq!( bv.bit_shr(3), bit_vec![true, false, false, true, false,
+                                            true, true, false, false, false] );
+        assert_eq!( bv.bit_shl_fill(2, true), bit_vec![true, true, true, false, true,
+                                                      true, false, false, true, false] );
+        assert_eq!( bv.bit_shr_fill(9, true), bit_vec![true; 10] );
+        assert_eq!( bv.bit_shl(0), bv );
+        assert_eq!( bv.bit_shr(100), bit_vec![false; 10] );
+
+        let both = bv.bit_shl(1).into_bit_and(bv.bit_shr(1)).to_bit_vec();
+        assert_eq!( both, bit_vec![false, true, false, false, false,
+                                   false, false, true, false, false] );
+    }
+
     #[test]
     fn mixed_equality() {
         let bv1: BitVec = bit_vec![false, false, true, true];
         let bv2: BitVec = bit_vec![false, true, false, true];
         assert_eq!( bv1.bit_xor(&bv2), bit_vec![false, true, true, false] );
     }
+
+    #[test]
+    fn interleave_three() {
+        let x: BitVec<u8> = BitVec::new_fill(true, 11);
+        let y: BitVec<u8> = BitVec::new_fill(false, 12);
+        let z: BitVec<u8> = bit_vec![true, false, true, false, true, false,
+                                     true, false, true, false, true, false];
+
+        let inputs = [x.as_slice(), y.as_slice(), z.as_slice()];
+        let xyz = super::bit_interleave(&inputs);
+        assert_eq!( xyz.bit_len(), 33 );
+        assert_eq!( xyz.ones().collect::<Vec<_>>(),
+                    vec![0, 2, 3, 6, 8, 9, 12, 14, 15, 18, 20, 21, 24, 26, 27, 30, 32] );
+
+        assert_eq!( xyz.bit_deinterleave(3, 0), x );
+        assert_eq!( xyz.bit_deinterleave(3, 1), y.bit_slice(.. 11) );
+        assert_eq!( xyz.bit_deinterleave(3, 2), z.bit_slice(.. 11) );
+        assert_eq!( xyz.bit_slice(4 .. 10), bit_vec![false, false, true, false, true, true] );
+    }
+
+    #[test]
+    #[should_panic]
+    fn deinterleave_bad_component() {
+        let bv: BitVec = bit_vec![true, false];
+        bv.bit_deinterleave(2, 2);
+    }
+
+    #[cfg(int_128)]
+    #[test]
+    fn interleave_u128() {
+        let x: BitVec<u128> = BitVec::new_fill(true, 200);
+        let y: BitVec<u128> = BitVec::new_fill(false, 200);
+        let inputs = [&x, &y];
+        let xy = super::bit_interleave(&inputs);
+
+        assert_eq!( xy.count_ones(), 200 );
+        assert_eq!( xy.get_block(1), 0x5555_5555_5555_5555_5555_5555_5555_5555 );
+        assert_eq!( xy.bit_deinterleave(2, 0), x );
+        assert_eq!( xy.bit_deinterleave(2, 1), y );
+    }
+
+    // The bit vector of length `len` whose bit `i` is `f(i)`.
+    fn from_fn<F: FnMut(usize) -> bool>(len: usize, mut f: F) -> BitVec<u8> {
+        let mut result = BitVec::new();
+        for i in 0 .. len {
+            result.push(f(i));
+        }
+        result
+    }
+
+    // Whether `bits` matches `expected` both when compared a block at a time
+    // and when copied out.
+    fn same_bits<T: Bits<Block = u8>>(bits: T, expected: &BitVec<u8>) -> bool {
+        BlockIter::new(&bits) == BlockIter::new(expected) && bits.to_bit_vec() == *expected
+    }
+
+    fn check_interleave<Block: BlockType>(inputs: &[Vec<bool>]) -> bool {
+        let inputs: Vec<BitVec<Block>> = inputs.iter().take(9).map(|bits| {
+            let mut bv = BitVec::new();
+            for &bit in bits {
+                bv.push(bit);
+            }
+            bv
+        }).collect();
+        let k   = inputs.len();
+        let len = inputs.iter().map(|bv| bv.len()).min().unwrap_or(0);
+
+        let mut expected: BitVec<Block> = BitVec::new();
+        for i in 0 .. len {
+            for input in &inputs {
+                expected.push(input[i]);
+            }
+        }
+
+        let interleaved = super::bit_interleave(&inputs);
+
+        interleaved == expected
+            && interleaved.to_bit_vec() == expected
+            && (0 .. k).all(|j| {
+                interleaved.bit_deinterleave(k, j) == inputs[j].bit_slice(.. len)
+                    && expected.bit_deinterleave(k, j).to_bit_vec() == inputs[j].bit_slice(.. len)
+            })
+    }
+
+    quickcheck! {
+        fn prop_interleave(inputs: Vec<Vec<bool>>) -> bool {
+            check_interleave::<u8>(&inputs)
+                && check_interleave::<u16>(&inputs)
+                && check_interleave::<u32>(&inputs)
+                && check_interleave::<u64>(&inputs)
+        }
+    }
+
+    quickcheck! {
+        fn prop_with_block(bits: Vec<bool>, start: usize) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let start = if bits.is_empty() { 0 } else { (start % bits.len()) as u64 };
+            let slice = bv.bit_slice(start ..);
+
+            let wide: BitVec<u64> = slice.with_block::<u64>().to_bit_vec();
+            let mid = wide.as_slice().into_with_block::<u16>();
+            let back: BitVec<u32> = mid.clone().into_with_block::<u32>().to_bit_vec();
+
+            wide.len() == slice.len()
+                && (0 .. wide.len()).all(|i| wide[i] == slice[i])
+                && mid.with_block::<u8>() == slice
+                && back.with_block::<u8>() == slice
+        }
+    }
+
+    #[test]
+    fn and_not() {
+        let bv1: BitVec<u8> = BitVec::new_fill(true, 12);
+        let bv2: BitVec<u8> = bit_vec![true, false, true, false, true, false,
+                                       true, false, true, false];
+        let diff = bv1.bit_and_not(&bv2);
+
+        assert_eq!( diff.bit_len(), 10 );
+        assert_eq!( diff.get_raw_block(0), 0b1010_1010 );
+        assert_eq!( diff.get_block(1), 0b10 );
+        assert_eq!( diff.bit_slice(1..4), bit_vec![true, false, true] );
+    }
+
+    quickcheck! {
+        fn prop_logic(bits1: Vec<bool>, bits2: Vec<bool>) -> bool {
+            let bv1: BitVec<u8> = BitVec::from_bits(&bits1);
+            let bv2: BitVec<u8> = BitVec::from_bits(&bits2);
+            let len = bv1.len().min(bv2.len()) as usize;
+
+            let expected = |op: fn(bool, bool) -> bool| {
+                from_fn(len, |i| op(bits1[i], bits2[i]))
+            };
+            let assigned = |assign: fn(&mut BitVec<u8>, BitSlice<u8>)| -> BitVec<u8> {
+                let mut result = bv1.bit_slice(.. len as u64).to_bit_vec();
+                assign(&mut result, bv2.bit_slice(.. len as u64));
+                result
+            };
+
+            let and_not = expected(|a, b| a & !b);
+            let nand    = expected(|a, b| !(a & b));
+            let nor     = expected(|a, b| !(a | b));
+            let xnor    = expected(|a, b| a == b);
+            let implies = expected(|a, b| !a | b);
+
+            same_bits(bv1.bit_and_not(&bv2), &and_not)
+                && same_bits(bv1.bit_nand(&bv2), &nand)
+                && same_bits(bv1.bit_nor(&bv2), &nor)
+                && same_bits(bv1.bit_xnor(&bv2), &xnor)
+                && same_bits(bv1.bit_implies(&bv2), &implies)
+                && assigned(|v, w| v.bit_and_not_assign(w)) == and_not
+                && assigned(|v, w| v.bit_nand_assign(w)) == nand
+                && assigned(|v, w| v.bit_nor_assign(w)) == nor
+                && assigned(|v, w| v.bit_xnor_assign(w)) == xnor
+                && assigned(|v, w| v.bit_implies_assign(w)) == implies
+        }
+    }
+
+    #[test]
+    fn zip3_slice() {
+        let a: BitVec<u8> = BitVec::new_fill(true, 20);
+        let b: BitVec<u8> = BitVec::new_fill(false, 18);
+        let c: BitVec<u8> = bit_vec![true, false, true, false, true, false, true, false,
+                                     true, false, true, false, true, false, true, false,
+                                     true, false, true, false, true, false];
+        let zipped = a.bit_zip3(&b, &c, |x, y, z| (x & y) | z);
+
+        assert_eq!( zipped.bit_len(), 18 );
+        assert_eq!( zipped.get_block(2), 0b01 );
+        assert!( zipped.bit_slice(15..18) == bit_vec![false, true, false] );
+    }
+
+    #[test]
+    fn reduce_empty() {
+        let inputs: [BitVec; 0] = [];
+        assert_eq!( super::BitReduce::or_all(&inputs).bit_len(), 0 );
+        assert_eq!( super::BitReduce::threshold(&inputs, 0).bit_len(), 0 );
+    }
+
+    quickcheck! {
+        fn prop_select(mask: Vec<bool>, bits1: Vec<bool>, bits2: Vec<bool>) -> bool {
+            let mask_bv: BitVec<u8> = BitVec::from_bits(&mask);
+            let bv1:     BitVec<u8> = BitVec::from_bits(&bits1);
+            let bv2:     BitVec<u8> = BitVec::from_bits(&bits2);
+            let len = mask.len().min(bits1.len()).min(bits2.len());
+
+            let expected = from_fn(len, |i| if mask[i] { bits1[i] } else { bits2[i] });
+
+            same_bits(mask_bv.bit_select(&bv1, &bv2), &expected)
+        }
+    }
+
+    quickcheck! {
+        fn prop_reduce(inputs: Vec<Vec<bool>>, k: usize) -> bool {
+            let bvs: Vec<BitVec<u8>> = inputs.iter().take(20).map(BitVec::from_bits).collect();
+            let n   = bvs.len();
+            let k   = k % (n + 2);
+            let len = inputs.iter().take(20).map(Vec::len).min().unwrap_or(0);
+
+            let expected = |pred: &Fn(usize) -> bool| {
+                from_fn(len, |i| pred(inputs.iter().take(20).filter(|bits| bits[i]).count()))
+            };
+
+            same_bits(super::BitReduce::or_all(&bvs), &expected(&|count| count > 0))
+                && same_bits(super::BitReduce::and_all(&bvs), &expected(&|count| count == n))
+                && same_bits(super::BitReduce::majority(&bvs), &expected(&|count| 2 * count > n))
+                && same_bits(super::BitReduce::threshold(&bvs, k), &expected(&|count| count >= k))
+        }
+    }
+
+    quickcheck! {
+        fn prop_length_policy(bits1: Vec<bool>, bits2: Vec<bool>, fill: bool) -> bool {
+            let bv1: BitVec<u8> = BitVec::from_bits(&bits1);
+            let bv2: BitVec<u8> = BitVec::from_bits(&bits2);
+            let (len1, len2) = (bits1.len(), bits2.len());
+            let or = |len: usize, pad: bool| from_fn(len, |i| {
+                let b1 = if i < len1 { bits1[i] } else { pad };
+                let b2 = if i < len2 { bits2[i] } else { pad };
+                b1 | b2
+            });
+
+            let extended_or = or(len1.max(len2), fill);
+
+            let mut assigned = bv1.clone();
+            assigned.bit_or_assign_with(&bv2, LengthPolicy::ExtendWith(fill)).unwrap();
+            let mut strict_assigned = bv1.clone();
+            let strict_result = strict_assigned.bit_or_assign_with(&bv2, LengthPolicy::Strict);
+
+            same_bits(bv1.bit_or_with(&bv2, LengthPolicy::Truncate).unwrap(),
+                      &or(len1.min(len2), false))
+                && same_bits(bv1.bit_or_with(&bv2, LengthPolicy::ExtendWith(fill)).unwrap(),
+                             &extended_or)
+                && same_bits(bv1.bit_zip_with(&bv2, |b1, b2| b1 | b2,
+                                              LengthPolicy::ExtendWith(fill)).unwrap(),
+                             &extended_or)
+                && assigned == or(len1, fill)
+                && (len1 == len2) == bv1.bit_or_with(&bv2, LengthPolicy::Strict).is_ok()
+                && (len1 == len2) == strict_result.is_ok()
+                && (len1 == len2 || strict_assigned == bv1)
+        }
+    }
 }
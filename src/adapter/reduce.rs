@@ -0,0 +1,159 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+
+use traits::get_masked_block;
+
+/// Combines the bits at each position of several bit-vector-likes,
+/// counting how many of them are set.
+///
+/// The result has the length of the shortest input. Each block of the
+/// result is computed from one block of each input, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use bv::*;
+/// use bv::adapter::BitReduce;
+///
+/// let a: BitVec = bit_vec![true,  true,  false, false];
+/// let b: BitVec = bit_vec![true,  false, true,  false];
+/// let c: BitVec = bit_vec![true,  true,  true,  false];
+///
+/// let replicas = [&a, &b, &c];
+/// assert_eq!( BitReduce::or_all(&replicas),   bit_vec![true, true, true, false] );
+/// assert_eq!( BitReduce::and_all(&replicas),  bit_vec![true, false, false, false] );
+/// assert_eq!( BitReduce::majority(&replicas), bit_vec![true, true, true, false] );
+/// assert_eq!( BitReduce::threshold(&replicas, 3), bit_vec![true, false, false, false] );
+/// ```
+#[derive(Debug)]
+pub struct BitReduce<'a, T: 'a> {
+    inputs: &'a [T],
+    op:     ReduceOp,
+    len:    u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ReduceOp {
+    Or,
+    And,
+    Threshold(usize),
+}
+
+impl<'a, T: 'a> Clone for BitReduce<'a, T> {
+    fn clone(&self) -> Self {
+        BitReduce { inputs: self.inputs, op: self.op, len: self.len }
+    }
+}
+
+impl<'a, T: Bits> BitReduce<'a, T> {
+    fn new(inputs: &'a [T], op: ReduceOp) -> Self {
+        let len = inputs.iter().map(Bits::bit_len).min().unwrap_or(0);
+        BitReduce { inputs, op, len }
+    }
+
+    /// Sets each bit where any of the inputs is set.
+    pub fn or_all(inputs: &'a [T]) -> Self {
+        Self::new(inputs, ReduceOp::Or)
+    }
+
+    /// Sets each bit where all of the inputs are set.
+    pub fn and_all(inputs: &'a [T]) -> Self {
+        Self::new(inputs, ReduceOp::And)
+    }
+
+    /// Sets each bit where at least `k` of the inputs are set.
+    pub fn threshold(inputs: &'a [T], k: usize) -> Self {
+        Self::new(inputs, ReduceOp::Threshold(k))
+    }
+
+    /// Sets each bit where more than half of the inputs are set.
+    pub fn majority(inputs: &'a [T]) -> Self {
+        Self::threshold(inputs, inputs.len() / 2 + 1)
+    }
+
+    // Counts the set bits at each position in a vertical counter, whose
+    // `p`th block holds bit `p` of every count, and then compares the
+    // counts against `k` one bit plane at a time from the top.
+    fn threshold_block(&self, position: usize, k: usize) -> T::Block {
+        let n = self.inputs.len();
+        if k == 0 { return !T::Block::zero(); }
+        if k > n  { return T::Block::zero(); }
+
+        let planes = 64 - (n as u64).leading_zeros() as usize;
+        let mut counter = [T::Block::zero(); 64];
+
+        for input in self.inputs {
+            let mut carry = input.get_raw_block(position);
+            for plane in &mut counter[.. planes] {
+                let next = *plane & carry;
+                *plane = *plane ^ carry;
+                carry = next;
+            }
+        }
+
+        let mut greater = T::Block::zero();
+        let mut equal   = !T::Block::zero();
+
+        for p in (0 .. planes).rev() {
+            if k >> p & 1 == 1 {
+                equal = equal & counter[p];
+            } else {
+                greater = greater | (equal & counter[p]);
+                equal = equal & !counter[p];
+            }
+        }
+
+        greater | equal
+    }
+}
+
+impl<'a, T: Bits> Bits for BitReduce<'a, T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitReduce::get_block: out of bounds" );
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!( position < self.block_len(), "BitReduce::get_raw_block: out of bounds" );
+
+        match self.op {
+            ReduceOp::Or =>
+                self.inputs.iter().fold(Self::Block::zero(), |acc, input|
+                    acc | input.get_raw_block(position)),
+            ReduceOp::And =>
+                self.inputs.iter().fold(!Self::Block::zero(), |acc, input|
+                    acc & input.get_raw_block(position)),
+            ReduceOp::Threshold(k) =>
+                self.threshold_block(position, k),
+        }
+    }
+}
+
+impl_index_from_bits! {
+    impl['a, T: Bits] Index<u64> for BitReduce<'a, T>;
+}
+
+impl_bit_ops_from_bits! {
+    impl['a, T: Bits] BitOps for BitReduce<'a, T>;
+}
+
+impl_bit_sliceable_adapter! {
+    impl['a, T: Bits] BitSliceable for BitReduce<'a, T>;
+    impl['a, 'b, T: Bits] BitSliceable for &'b BitReduce<'a, T>;
+}
+
+impl<'a, T, U> PartialEq<U> for BitReduce<'a, T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
@@ -0,0 +1,69 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+
+use traits::get_masked_block;
+
+/// The result of
+/// [`BitsExt::bit_reverse`](../trait.BitsExt.html#method.bit_reverse).
+///
+/// The resulting bit vector adapter presents the bits of the underlying
+/// bit-vector-like in reverse order.
+#[derive(Clone, Debug)]
+pub struct BitReverse<T>(T);
+
+impl<T: Bits> BitReverse<T> {
+    pub (crate) fn new(bits: T) -> Self {
+        BitReverse(bits)
+    }
+}
+
+impl<T: Bits> Bits for BitReverse<T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.0.bit_len()
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!(position < self.bit_len(), "BitReverse::get_bit: out of bounds");
+        self.0.get_bit(self.bit_len() - 1 - position)
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!(position < self.block_len(), "BitReverse::get_raw_block: out of bounds");
+
+        // Block `position` of the result holds, reversed, the `count` bits
+        // that end `start` bits from the end of the source.
+        let start = Self::Block::mul_nbits(position);
+        let count = Self::Block::block_bits(self.bit_len(), position);
+        let bits  = self.0.get_bits(self.bit_len() - start - count as u64, count);
+        bits.reverse_bits() >> (Self::Block::nbits() - count)
+    }
+}
+
+impl_index_from_bits! {
+    impl[T: Bits] Index<u64> for BitReverse<T>;
+}
+
+impl_bit_ops_from_bits! {
+    impl[T: Bits] BitOps for BitReverse<T>;
+}
+
+impl_bit_sliceable_adapter! {
+    impl[T: Bits] BitSliceable for BitReverse<T>;
+    impl['a, T: Bits] BitSliceable for &'a BitReverse<T>;
+}
+
+impl<T, U> PartialEq<U> for BitReverse<T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
@@ -0,0 +1,166 @@
+use Bits;
+use BlockType;
+use iter::BlockIter;
+
+use traits::get_masked_block;
+
+use std::cmp;
+
+/// The result of [`BitsExt::bit_shl`](../trait.BitsExt.html#method.bit_shl).
+///
+/// The resulting bit vector adapter has the same length as the underlying
+/// bit-vector-like, with its bits moved `shift` places toward the higher
+/// indices. The vacated low positions hold the fill bit, and the bits
+/// shifted past the end are lost.
+#[derive(Clone, Debug)]
+pub struct BitShl<T> {
+    bits:  T,
+    shift: u64,
+    fill:  bool,
+}
+// Invariant: shift <= bits.bit_len()
+
+/// The result of [`BitsExt::bit_shr`](../trait.BitsExt.html#method.bit_shr).
+///
+/// The resulting bit vector adapter has the same length as the underlying
+/// bit-vector-like, with its bits moved `shift` places toward the lower
+/// indices. The vacated high positions hold the fill bit, and the bits
+/// shifted past the start are lost.
+#[derive(Clone, Debug)]
+pub struct BitShr<T> {
+    bits:  T,
+    shift: u64,
+    fill:  bool,
+}
+// Invariant: shift <= bits.bit_len()
+
+impl<T: Bits> BitShl<T> {
+    pub (crate) fn new(bits: T, shift: u64, fill: bool) -> Self {
+        let shift = cmp::min(shift, bits.bit_len());
+        BitShl { bits, shift, fill }
+    }
+}
+
+impl<T: Bits> BitShr<T> {
+    pub (crate) fn new(bits: T, shift: u64, fill: bool) -> Self {
+        let shift = cmp::min(shift, bits.bit_len());
+        BitShr { bits, shift, fill }
+    }
+}
+
+// Reads the `count` bits starting at `position` from a view in which `bits`
+// occupies positions `offset .. offset + bits.bit_len()` and every other
+// position holds `fill`. The part that overlaps `bits` is read with a single
+// `get_bits`, which combines at most two underlying blocks.
+fn read_shifted<T: Bits>(bits: &T, offset: u64, fill: bool,
+                         position: u64, count: usize) -> T::Block {
+
+    let fill_block = if fill { !T::Block::zero() } else { T::Block::zero() };
+
+    let start = cmp::max(position, offset);
+    let limit = cmp::min(position + count as u64, offset + bits.bit_len());
+
+    if start < limit {
+        let chunk = bits.get_bits(start - offset, (limit - start) as usize);
+        fill_block.with_bits((start - position) as usize, (limit - start) as usize, chunk)
+    } else {
+        fill_block
+    }
+}
+
+impl<T: Bits> Bits for BitShl<T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.bits.bit_len()
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!(position < self.bit_len(), "BitShl::get_bit: out of bounds");
+
+        if position < self.shift {
+            self.fill
+        } else {
+            self.bits.get_bit(position - self.shift)
+        }
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!(position < self.block_len(), "BitShl::get_raw_block: out of bounds");
+
+        let start = Self::Block::mul_nbits(position);
+        let count = Self::Block::block_bits(self.bit_len(), position);
+        read_shifted(&self.bits, self.shift, self.fill, start, count)
+    }
+}
+
+impl<T: Bits> Bits for BitShr<T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.bits.bit_len()
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!(position < self.bit_len(), "BitShr::get_bit: out of bounds");
+
+        if position + self.shift < self.bit_len() {
+            self.bits.get_bit(position + self.shift)
+        } else {
+            self.fill
+        }
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Self::Block {
+        assert!(position < self.block_len(), "BitShr::get_raw_block: out of bounds");
+
+        // Each result block reads the source `shift` bits further along,
+        // with fill past the source's end.
+        let start = Self::Block::mul_nbits(position) + self.shift;
+        let count = Self::Block::block_bits(self.bit_len(), position);
+        read_shifted(&self.bits, 0, self.fill, start, count)
+    }
+}
+
+impl_index_from_bits! {
+    impl[T: Bits] Index<u64> for BitShl<T>;
+    impl[T: Bits] Index<u64> for BitShr<T>;
+}
+
+impl_bit_ops_from_bits! {
+    impl[T: Bits] BitOps for BitShl<T>;
+    impl[T: Bits] BitOps for BitShr<T>;
+}
+
+impl_bit_sliceable_adapter! {
+    impl[T: Bits] BitSliceable for BitShl<T>;
+    impl['a, T: Bits] BitSliceable for &'a BitShl<T>;
+    impl[T: Bits] BitSliceable for BitShr<T>;
+    impl['a, T: Bits] BitSliceable for &'a BitShr<T>;
+}
+
+impl<T, U> PartialEq<U> for BitShl<T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
+
+impl<T, U> PartialEq<U> for BitShr<T>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    fn eq(&self, other: &U) -> bool {
+        BlockIter::new(self) == BlockIter::new(other)
+    }
+}
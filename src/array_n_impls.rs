@@ -3,6 +3,8 @@
 
 use {BlockType, Bits, BitsMut, BitSliceable};
 
+use std::ops::Range;
+
 macro_rules! impl_traits_for_array {
     (
         $( $size:tt )+
@@ -28,6 +30,14 @@ macro_rules! impl_traits_for_array {
                 fn set_block(&mut self, position: usize, value: Block) {
                     self[position] = value;
                 }
+
+                fn set_range(&mut self, range: Range<u64>, value: bool) {
+                    <[Block]>::set_range(self, range, value);
+                }
+
+                fn toggle_range(&mut self, range: Range<u64>) {
+                    <[Block]>::toggle_range(self, range);
+                }
             }
 
             impl<'a, R, Block: BlockType> BitSliceable<R> for &'a [Block; $size]
@@ -0,0 +1,218 @@
+//! Linear algebra over GF(2), the field of two elements, where addition
+//! is *xor* and multiplication is *and*.
+
+use {Bits, BitsMut, BitsMutExt, BitVec};
+use storage::BlockType;
+use super::BitMatrix;
+
+impl<Block: BlockType> BitMatrix<Block> {
+    /// Swaps rows `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// If either row is out of bounds.
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        assert!( i < self.rows && j < self.rows, "BitMatrix::swap_rows: out of bounds" );
+
+        if i != j {
+            for k in 0 .. self.stride {
+                self.blocks.swap(i * self.stride + k, j * self.stride + k);
+            }
+        }
+    }
+
+    // Adds row `src` to row `dest` over GF(2), where `src != dest`.
+    fn xor_row(&mut self, src: usize, dest: usize) {
+        let stride = self.stride;
+
+        let (src_blocks, dest_blocks) = if src < dest {
+            let (low, high) = self.blocks.split_at_mut(dest * stride);
+            (&low[src * stride .. (src + 1) * stride], &mut high[.. stride])
+        } else {
+            let (low, high) = self.blocks.split_at_mut(src * stride);
+            (&high[.. stride], &mut low[dest * stride .. (dest + 1) * stride])
+        };
+
+        dest_blocks.bit_xor_assign(src_blocks);
+    }
+
+    /// Brings the matrix into reduced row echelon form over GF(2) by
+    /// Gauss–Jordan elimination, returning its rank.
+    ///
+    /// Afterward, each of the first `rank` rows has a leading one (its
+    /// pivot), which is the only one in its column, and the pivots move
+    /// strictly to the right going down. The remaining rows are zero.
+    ///
+    /// Each step picks the remaining row whose first set bit is leftmost
+    /// as the pivot row, and then eliminates that column from the other
+    /// rows with [`BitsMutExt::bit_xor_assign`], a block at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let rows: Vec<BitVec> = vec![ bit_vec![false, true, true],
+    ///                               bit_vec![true, true, false],
+    ///                               bit_vec![true, false, true] ];
+    /// let mut matrix = BitMatrix::from_rows(3, &rows);
+    ///
+    /// assert_eq!( matrix.row_reduce(), 2 );
+    /// assert_eq!( matrix.row(0), bit_vec![true, false, true] );
+    /// assert_eq!( matrix.row(1), bit_vec![false, true, true] );
+    /// assert_eq!( matrix.row(2), bit_vec![false, false, false] );
+    /// ```
+    ///
+    /// [`BitsMutExt::bit_xor_assign`]: trait.BitsMutExt.html#method.bit_xor_assign
+    pub fn row_reduce(&mut self) -> usize {
+        let mut rank = 0;
+        let mut col  = 0;
+
+        // Invariant: rows `rank ..` are zero in columns `.. col`.
+        while rank < self.rows {
+            let mut pivot: Option<(u64, usize)> = None;
+
+            for r in rank .. self.rows {
+                match (self.row(r).next_one(col), pivot) {
+                    (Some(c), Some((best, _))) if c >= best => (),
+                    (Some(c), _) => {
+                        pivot = Some((c, r));
+                        if c == col { break; }
+                    }
+                    (None, _) => (),
+                }
+            }
+
+            let (pivot_col, pivot_row) = match pivot {
+                Some(pivot) => pivot,
+                None        => break,
+            };
+
+            self.swap_rows(rank, pivot_row);
+
+            for r in 0 .. self.rows {
+                if r != rank && self.get(r, pivot_col) {
+                    self.xor_row(rank, r);
+                }
+            }
+
+            rank += 1;
+            col = pivot_col + 1;
+        }
+
+        rank
+    }
+
+    /// The rank of the matrix over GF(2).
+    ///
+    /// This row reduces a copy of the matrix.
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce()
+    }
+
+    /// Returns a basis of the nullspace of the matrix over GF(2), that is,
+    /// of the vectors `x` such that `self` times `x` is zero.
+    ///
+    /// The basis vectors are the rows of the result, which has
+    /// `self.num_cols()` columns and one row for each column of `self`
+    /// without a pivot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let rows: Vec<BitVec> = vec![ bit_vec![true, true, false],
+    ///                               bit_vec![false, true, true] ];
+    /// let matrix = BitMatrix::from_rows(3, &rows);
+    ///
+    /// let nullspace = matrix.nullspace();
+    /// assert_eq!( nullspace.num_rows(), 1 );
+    /// assert_eq!( nullspace.row(0), bit_vec![true, true, true] );
+    /// ```
+    pub fn nullspace(&self) -> BitMatrix<Block> {
+        let mut reduced = self.clone();
+        let rank        = reduced.row_reduce();
+        let pivots      = reduced.pivots(rank);
+
+        let free: Vec<u64> = (0 .. self.cols)
+            .filter(|c| pivots.binary_search(c).is_err())
+            .collect();
+
+        // Setting free variable `f` to one forces each pivot variable to
+        // the entry in column `f` of the pivot's row.
+        let mut result = BitMatrix::new(free.len(), self.cols);
+        for (k, &f) in free.iter().enumerate() {
+            result.set(k, f, true);
+            for (i, &p) in pivots.iter().enumerate() {
+                if reduced.get(i, f) {
+                    result.set(k, p, true);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Solves `self` times `x` equals `b` over GF(2), returning a solution
+    /// `x`, or `None` if the system is inconsistent.
+    ///
+    /// When there are many solutions, this returns the one whose free
+    /// variables are all zero; adding any combination of the rows of
+    /// [`nullspace`](#method.nullspace) gives the others.
+    ///
+    /// # Panics
+    ///
+    /// If `b.bit_len() != self.num_rows() as u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let rows: Vec<BitVec> = vec![ bit_vec![true, true, false],
+    ///                               bit_vec![false, true, true],
+    ///                               bit_vec![true, false, true] ];
+    /// let matrix = BitMatrix::from_rows(3, &rows);
+    ///
+    /// let x = matrix.solve(bit_vec![true, false, true]).unwrap();
+    /// assert_eq!( x, bit_vec![true, false, false] );
+    ///
+    /// assert_eq!( matrix.solve(bit_vec![true, false, false]), None );
+    /// ```
+    pub fn solve<T: Bits<Block = Block>>(&self, b: T) -> Option<BitVec<Block>> {
+        assert_eq!( b.bit_len(), self.rows as u64,
+                    "BitMatrix::solve: wrong length" );
+
+        // Row reduce the augmented matrix `[self | b]`.
+        let mut augmented = BitMatrix::new(self.rows, self.cols + 1);
+        for i in 0 .. self.rows {
+            let mut row = augmented.row_mut(i);
+            for j in 0 .. self.stride {
+                row.set_block(j, self.blocks[i * self.stride + j]);
+            }
+            row.set_bit(self.cols, b.get_bit(i as u64));
+        }
+
+        let rank   = augmented.row_reduce();
+        let pivots = augmented.pivots(rank);
+
+        // A pivot in the last column means that `0 = 1`.
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+
+        let mut result = BitVec::new_fill(false, self.cols);
+        for (i, &p) in pivots.iter().enumerate() {
+            result.set_bit(p, augmented.get(i, self.cols));
+        }
+
+        Some(result)
+    }
+
+    // The pivot columns of a matrix in reduced row echelon form with the
+    // given rank, in increasing order.
+    fn pivots(&self, rank: usize) -> Vec<u64> {
+        (0 .. rank).map(|i| self.row(i).first_one().unwrap()).collect()
+    }
+}
@@ -0,0 +1,401 @@
+use {Bits, BitsExt, BitsMut, BitsPush, BitSliceable, BitVec, BitSlice, BitSliceMut};
+use storage::BlockType;
+
+use std::fmt;
+
+mod gf2;
+
+#[cfg(test)]
+mod test;
+
+/// A two-dimensional bit matrix.
+///
+/// The rows are stored contiguously in a single array of `Block`s, with
+/// each row padded to a whole number of blocks. Rows are accessed as
+/// [`BitSlice`]s and [`BitSliceMut`]s, so the whole [`Bits`] and
+/// [`BitsMut`] API is available for working with a row.
+///
+/// Rows are indexed by `usize` and columns, like other bit positions, by
+/// `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bv::*;
+///
+/// let mut matrix: BitMatrix<u8> = BitMatrix::new(2, 3);
+/// matrix.set(0, 1, true);
+/// matrix.set(1, 2, true);
+///
+/// assert_eq!( matrix.row(0), bit_vec![false, true, false] );
+/// assert_eq!( matrix.column(2), bit_vec![false, true] );
+///
+/// let transpose = matrix.transpose();
+/// assert_eq!( transpose.num_rows(), 3 );
+/// assert!( transpose.get(1, 0) );
+/// assert!( transpose.get(2, 1) );
+/// ```
+///
+/// [`BitSlice`]: struct.BitSlice.html
+/// [`BitSliceMut`]: struct.BitSliceMut.html
+/// [`Bits`]: trait.Bits.html
+/// [`BitsMut`]: trait.BitsMut.html
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BitMatrix<Block = usize> {
+    blocks: Vec<Block>,
+    rows:   usize,
+    cols:   u64,
+    stride: usize,
+}
+// Invariants:
+//   stride == Block::ceil_div_nbits(cols)
+//   blocks.len() == rows * stride
+//   the padding bits at the end of each row are all zero
+
+impl<Block: BlockType> BitMatrix<Block> {
+    /// Creates a new matrix of all zeros with the given dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let matrix: BitMatrix = BitMatrix::new(3, 100);
+    /// assert_eq!( matrix.num_rows(), 3 );
+    /// assert_eq!( matrix.num_cols(), 100 );
+    /// assert!( !matrix.get(2, 99) );
+    /// ```
+    pub fn new(rows: usize, cols: u64) -> Self {
+        let stride = Block::ceil_div_nbits(cols);
+        let size   = rows.checked_mul(stride).expect("BitMatrix::new: overflow");
+
+        BitMatrix {
+            blocks: vec![Block::zero(); size],
+            rows,
+            cols,
+            stride,
+        }
+    }
+
+    /// Creates a new matrix with the given dimensions, filled with all 0s
+    /// or 1s depending on `value`.
+    pub fn new_fill(value: bool, rows: usize, cols: u64) -> Self {
+        let mut result = Self::new(rows, cols);
+
+        if value {
+            for i in 0 .. rows {
+                result.row_mut(i).set_range(0 .. cols, true);
+            }
+        }
+
+        result
+    }
+
+    /// Creates a matrix from an iterator of rows, each of which must have
+    /// `cols` bits.
+    ///
+    /// # Panics
+    ///
+    /// If any row does not have length `cols`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let rows: Vec<BitVec> = vec![ bit_vec![true, false], bit_vec![true, true] ];
+    /// let matrix = BitMatrix::from_rows(2, &rows);
+    ///
+    /// assert_eq!( matrix.column(0), bit_vec![true, true] );
+    /// assert_eq!( matrix.column(1), bit_vec![false, true] );
+    /// ```
+    pub fn from_rows<I>(cols: u64, rows: I) -> Self
+        where I: IntoIterator,
+              I::Item: Bits<Block = Block> {
+
+        let stride = Block::ceil_div_nbits(cols);
+
+        let mut blocks = Vec::new();
+        let mut count  = 0;
+
+        for row in rows {
+            assert_eq!( row.bit_len(), cols,
+                        "BitMatrix::from_rows: row has wrong length" );
+            blocks.extend((0 .. stride).map(|i| row.get_block(i)));
+            count += 1;
+        }
+
+        BitMatrix { blocks, rows: count, cols, stride }
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns, which is the length of each row.
+    pub fn num_cols(&self) -> u64 {
+        self.cols
+    }
+
+    // The blocks of row `row`, including the padding.
+    fn row_blocks(&self, row: usize) -> &[Block] {
+        &self.blocks[row * self.stride .. (row + 1) * self.stride]
+    }
+
+    // The blocks of row `row`, including the padding.
+    fn row_blocks_mut(&mut self, row: usize) -> &mut [Block] {
+        &mut self.blocks[row * self.stride .. (row + 1) * self.stride]
+    }
+
+    /// Returns row `row` as a bit slice.
+    ///
+    /// # Panics
+    ///
+    /// If `row >= self.num_rows()`.
+    pub fn row(&self, row: usize) -> BitSlice<Block> {
+        assert!( row < self.rows, "BitMatrix::row: out of bounds" );
+        self.row_blocks(row).bit_slice(.. self.cols)
+    }
+
+    /// Returns row `row` as a mutable bit slice.
+    ///
+    /// # Panics
+    ///
+    /// If `row >= self.num_rows()`.
+    pub fn row_mut(&mut self, row: usize) -> BitSliceMut<Block> {
+        assert!( row < self.rows, "BitMatrix::row_mut: out of bounds" );
+        let cols = self.cols;
+        self.row_blocks_mut(row).bit_slice(.. cols)
+    }
+
+    /// Returns an iterator over the rows as bit slices.
+    pub fn rows(&self) -> Rows<Block> {
+        Rows { matrix: self, front: 0, back: self.rows }
+    }
+
+    /// Copies column `col` into a new bit-vector.
+    ///
+    /// # Panics
+    ///
+    /// If `col >= self.num_cols()`.
+    pub fn column(&self, col: u64) -> BitVec<Block> {
+        assert!( col < self.cols, "BitMatrix::column: out of bounds" );
+
+        let index  = Block::div_nbits(col);
+        let offset = Block::mod_nbits(col);
+        let nbits  = Block::nbits();
+
+        let mut result = BitVec::with_capacity(self.rows as u64);
+
+        let mut start = 0;
+        while start < self.rows {
+            let count = if self.rows - start < nbits { self.rows - start } else { nbits };
+
+            let mut block = Block::zero();
+            for k in 0 .. count {
+                let row_block = self.blocks[(start + k) * self.stride + index];
+                if row_block.get_bit(offset) {
+                    block = block | Block::nth_mask(k);
+                }
+            }
+
+            result.push_bits(block, count);
+            start += count;
+        }
+
+        result
+    }
+
+    /// Gets the bit at row `row` and column `col`.
+    ///
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    pub fn get(&self, row: usize, col: u64) -> bool {
+        assert!( row < self.rows && col < self.cols, "BitMatrix::get: out of bounds" );
+        self.row_blocks(row)[Block::div_nbits(col)].get_bit(Block::mod_nbits(col))
+    }
+
+    /// Sets the bit at row `row` and column `col` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    pub fn set(&mut self, row: usize, col: u64, value: bool) {
+        assert!( row < self.rows && col < self.cols, "BitMatrix::set: out of bounds" );
+        let block = &mut self.row_blocks_mut(row)[Block::div_nbits(col)];
+        *block = block.with_bit(Block::mod_nbits(col), value);
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// This works on square tiles of `Block::nbits()` by `Block::nbits()`
+    /// bits, such as 8×8 for `u8` or 64×64 for `u64`, transposing each
+    /// tile within a block array of its rows.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns does not fit in a `usize`.
+    pub fn transpose(&self) -> Self {
+        let new_rows = self.cols.to_usize().expect("BitMatrix::transpose: overflow");
+        let mut result = BitMatrix::new(new_rows, self.rows as u64);
+
+        let nbits    = Block::nbits();
+        let mut tile = vec![Block::zero(); nbits];
+
+        for tile_row in 0 .. result.stride {
+            let first_row = tile_row * nbits;
+            let count     = if self.rows - first_row < nbits { self.rows - first_row } else { nbits };
+
+            for tile_col in 0 .. self.stride {
+                for (k, slot) in tile.iter_mut().enumerate() {
+                    *slot = if k < count {
+                        self.blocks[(first_row + k) * self.stride + tile_col]
+                    } else {
+                        Block::zero()
+                    };
+                }
+
+                transpose_tile(&mut tile);
+
+                // Rows of the tile past the last column come from padding.
+                let first_col = tile_col * nbits;
+                for (k, &block) in tile.iter().enumerate().take(new_rows - first_col) {
+                    result.blocks[(first_col + k) * result.stride + tile_row] = block;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Multiplies two matrices over the Boolean semiring, where addition is
+    /// *or* and multiplication is *and*.
+    ///
+    /// Each row of the result is the *or* of the rows of `other` selected by
+    /// the set bits of the corresponding row of `self`, computed a block at
+    /// a time.
+    ///
+    /// # Panics
+    ///
+    /// If `self.num_cols() != other.num_rows() as u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut edges: BitMatrix<u8> = BitMatrix::new(3, 3);
+    /// edges.set(0, 1, true);
+    /// edges.set(1, 2, true);
+    ///
+    /// let two_steps = edges.bool_mul(&edges);
+    /// assert!( two_steps.get(0, 2) );
+    /// assert_eq!( two_steps.row(0).count_ones(), 1 );
+    /// ```
+    pub fn bool_mul(&self, other: &Self) -> Self {
+        assert_eq!( self.cols, other.rows as u64,
+                    "BitMatrix::bool_mul: dimensions do not match" );
+
+        let mut result = BitMatrix::new(self.rows, other.cols);
+
+        for i in 0 .. self.rows {
+            let target = result.row_blocks_mut(i);
+            for k in self.row(i).ones() {
+                let source = other.row_blocks(k as usize);
+                for (block, &other_block) in target.iter_mut().zip(source) {
+                    *block = *block | other_block;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+// Transposes the square bit matrix whose rows are the blocks of `tile`,
+// which has length `Block::nbits()`.
+//
+// Each round swaps the top-right and bottom-left quadrants of every square of
+// `2 * width` bits, for `width` halving from `Block::nbits() / 2` to 1. The
+// mask selects the low half of each group of `2 * width` bits.
+fn transpose_tile<Block: BlockType>(tile: &mut [Block]) {
+    let nbits     = Block::nbits();
+    let mut width = nbits / 2;
+    let mut mask  = Block::low_mask(width);
+
+    while width > 0 {
+        for k in 0 .. nbits {
+            if k & width == 0 {
+                let t = ((tile[k] >> width) ^ tile[k + width]) & mask;
+                tile[k + width] = tile[k + width] ^ t;
+                tile[k] = tile[k] ^ (t << width);
+            }
+        }
+
+        width /= 2;
+        mask = mask ^ (mask << width);
+    }
+}
+
+impl<Block: BlockType> fmt::Debug for BitMatrix<Block> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BitMatrix ")?;
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<'a, Block: BlockType> IntoIterator for &'a BitMatrix<Block> {
+    type Item = BitSlice<'a, Block>;
+    type IntoIter = Rows<'a, Block>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows()
+    }
+}
+
+/// An iterator over the rows of a [`BitMatrix`], as bit slices.
+///
+/// This struct is created by the [`rows`] method.
+///
+/// [`BitMatrix`]: ../struct.BitMatrix.html
+/// [`rows`]: ../struct.BitMatrix.html#method.rows
+#[derive(Clone, Debug)]
+pub struct Rows<'a, Block: BlockType + 'a> {
+    matrix: &'a BitMatrix<Block>,
+    front:  usize,
+    back:   usize,
+}
+
+impl<'a, Block: BlockType> Iterator for Rows<'a, Block> {
+    type Item = BitSlice<'a, Block>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.matrix.row(self.front - 1))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, Block: BlockType> DoubleEndedIterator for Rows<'a, Block> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.matrix.row(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Block: BlockType> ExactSizeIterator for Rows<'a, Block> {}
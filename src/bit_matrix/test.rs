@@ -0,0 +1,233 @@
+use super::*;
+
+// Builds a matrix from a function of its positions.
+fn tabulate<Block, F>(rows: usize, cols: u64, fun: F) -> BitMatrix<Block>
+    where Block: BlockType,
+          F: Fn(usize, u64) -> bool {
+
+    let mut result = BitMatrix::new(rows, cols);
+    for r in 0 .. rows {
+        for c in 0 .. cols {
+            result.set(r, c, fun(r, c));
+        }
+    }
+    result
+}
+
+// Multiplies a matrix by a column vector over GF(2).
+fn gf2_apply<Block: BlockType>(matrix: &BitMatrix<Block>, x: &BitVec<Block>) -> BitVec<Block> {
+    let mut result = BitVec::new();
+    for row in matrix {
+        result.push(row.bit_and(x).count_ones() % 2 == 1);
+    }
+    result
+}
+
+#[test]
+fn new_fill() {
+    let matrix: BitMatrix<u8> = BitMatrix::new_fill(true, 3, 11);
+    assert_eq!( matrix.rows().count(), 3 );
+    for row in &matrix {
+        assert_eq!( row.count_ones(), 11 );
+    }
+    assert_eq!( matrix, tabulate(3, 11, |_, _| true) );
+}
+
+#[test]
+fn rows_and_columns() {
+    let mut matrix: BitMatrix<u16> = tabulate(5, 20, |r, c| (r as u64 + c) % 3 == 1);
+
+    assert_eq!( matrix.row(1).ones().collect::<Vec<_>>(), vec![0, 3, 6, 9, 12, 15, 18] );
+    assert_eq!( matrix.column(4), bit_vec![true, false, false, true, false] );
+    assert_eq!( matrix.rows().next_back().unwrap(), matrix.row(4) );
+
+    matrix.row_mut(2).set_range(0 .. 20, false);
+    assert!( !matrix.get(2, 1) );
+    assert!( matrix.get(3, 1) );
+
+    let copy = BitMatrix::from_rows(20, matrix.rows());
+    assert_eq!( copy, matrix );
+}
+
+#[test]
+fn column_of_many_rows() {
+    let matrix: BitMatrix<u8> = tabulate(21, 3, |r, c| r % 4 == c as usize);
+    let column = matrix.column(1);
+    assert_eq!( column.len(), 21 );
+    assert_eq!( column.ones().collect::<Vec<_>>(), vec![1, 5, 9, 13, 17] );
+}
+
+#[test]
+#[should_panic]
+fn from_rows_wrong_length() {
+    let rows: Vec<BitVec> = vec![ bit_vec![true], bit_vec![true, false] ];
+    BitMatrix::from_rows(1, &rows);
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+    let matrix: BitMatrix = BitMatrix::new(2, 3);
+    matrix.get(0, 3);
+}
+
+#[test]
+fn transpose_tile_u8() {
+    let mut tile = [0b0000_0001u8, 0b0000_0011, 0, 0, 0, 0, 0, 0b1000_0000];
+    transpose_tile(&mut tile);
+    assert_eq!( tile, [0b0000_0011, 0b0000_0010, 0, 0, 0, 0, 0, 0b1000_0000] );
+}
+
+#[test]
+fn transpose_empty() {
+    let matrix: BitMatrix<u32> = BitMatrix::new(0, 40);
+    let transpose = matrix.transpose();
+    assert_eq!( transpose.num_rows(), 40 );
+    assert_eq!( transpose.num_cols(), 0 );
+    assert_eq!( transpose.transpose(), matrix );
+}
+
+#[test]
+fn bool_mul() {
+    // The successor relation on 0 .. 5 and its square.
+    let succ: BitMatrix<u8> = tabulate(5, 5, |r, c| c == r as u64 + 1);
+    let succ2 = succ.bool_mul(&succ);
+    assert_eq!( succ2, tabulate(5, 5, |r, c| c == r as u64 + 2) );
+
+    let rect: BitMatrix<u8> = tabulate(2, 5, |_, c| c == 0);
+    assert_eq!( rect.bool_mul(&succ), tabulate(2, 5, |_, c| c == 1) );
+}
+
+#[test]
+#[should_panic]
+fn bool_mul_bad_dimensions() {
+    let a: BitMatrix = BitMatrix::new(2, 3);
+    a.bool_mul(&a);
+}
+
+#[test]
+fn debug() {
+    let matrix: BitMatrix<u8> = tabulate(2, 2, |r, c| r as u64 == c);
+    assert_eq!( format!("{:?}", matrix),
+                "BitMatrix [bit_vec![true, false], bit_vec![false, true]]" );
+}
+
+#[test]
+fn row_reduce() {
+    let mut matrix: BitMatrix<u8> = tabulate(4, 10, |r, c| c == 9 - r as u64 || c == 9);
+    assert_eq!( matrix.row_reduce(), 4 );
+    assert_eq!( matrix, tabulate(4, 10, |r, c| c == 6 + r as u64 && c != 9 || r == 3 && c == 9) );
+
+    let mut zero: BitMatrix = BitMatrix::new(3, 5);
+    assert_eq!( zero.row_reduce(), 0 );
+    assert_eq!( zero, BitMatrix::new(3, 5) );
+    assert_eq!( zero.nullspace(), tabulate(5, 5, |r, c| r as u64 == c) );
+}
+
+#[test]
+fn swap_rows() {
+    let mut matrix: BitMatrix<u8> = tabulate(3, 12, |r, c| c == r as u64);
+    matrix.swap_rows(0, 2);
+    assert_eq!( matrix, tabulate(3, 12, |r, c| c == 2 - r as u64) );
+}
+
+#[test]
+fn solve_underdetermined() {
+    let matrix: BitMatrix<u16> = tabulate(2, 20, |r, c| c % 2 == r as u64);
+    let b = bit_vec![true, false];
+    let x = matrix.solve(&b).unwrap();
+    assert_eq!( x.ones().collect::<Vec<_>>(), vec![0] );
+    assert_eq!( matrix.nullspace().num_rows(), 18 );
+}
+
+#[test]
+#[should_panic]
+fn solve_wrong_length() {
+    let matrix: BitMatrix = BitMatrix::new(2, 3);
+    matrix.solve(BitVec::<usize>::new_fill(false, 3));
+}
+
+quickcheck! {
+    fn prop_transpose(rows: u8, cols: u8, seed: Vec<bool>) -> bool {
+        let rows = rows as usize % 70;
+        let cols = cols as u64 % 150;
+        let bit  = |r: usize, c: u64| {
+            !seed.is_empty() && seed[(r * 151 + c as usize) % seed.len()]
+        };
+
+        let matrix: BitMatrix<u64> = tabulate(rows, cols, bit);
+        let transpose = matrix.transpose();
+
+        transpose == tabulate(cols as usize, rows as u64, |r, c| bit(c as usize, r as u64))
+            && transpose.transpose() == matrix
+    }
+
+    fn prop_transpose_u8(rows: u8, cols: u8, seed: Vec<bool>) -> bool {
+        let rows = rows as usize % 30;
+        let cols = cols as u64 % 30;
+        let bit  = |r: usize, c: u64| {
+            !seed.is_empty() && seed[(r * 31 + c as usize) % seed.len()]
+        };
+
+        let matrix: BitMatrix<u8> = tabulate(rows, cols, bit);
+        matrix.transpose() == tabulate(cols as usize, rows as u64, |r, c| bit(c as usize, r as u64))
+    }
+
+    fn prop_bool_mul(seed1: Vec<bool>, seed2: Vec<bool>) -> bool {
+        let bit = |seed: &Vec<bool>, r: usize, c: u64| {
+            !seed.is_empty() && seed[(r * 13 + c as usize) % seed.len()]
+        };
+
+        let a: BitMatrix<u8> = tabulate(7, 12, |r, c| bit(&seed1, r, c));
+        let b: BitMatrix<u8> = tabulate(12, 9, |r, c| bit(&seed2, r, c));
+
+        a.bool_mul(&b) == tabulate(7, 9, |r, c| {
+            (0 .. 12).any(|k| a.get(r, k) && b.get(k as usize, c))
+        })
+    }
+
+    fn prop_gf2(seed: Vec<bool>, x: Vec<bool>) -> bool {
+        let bit = |r: usize, c: u64| {
+            !seed.is_empty() && seed[(r * 13 + c as usize) % seed.len()]
+        };
+
+        let matrix: BitMatrix<u8> = tabulate(9, 12, bit);
+        let rank      = matrix.rank();
+        let nullspace = matrix.nullspace();
+        let zero      = BitVec::new_fill(false, 9);
+
+        let mut x0: BitVec<u8> = BitVec::new_fill(false, 12);
+        for (i, &b) in x.iter().take(12).enumerate() {
+            x0.set(i as u64, b);
+        }
+        let b = gf2_apply(&matrix, &x0);
+        let solution = matrix.solve(&b).unwrap();
+
+        rank == matrix.transpose().rank()
+            && nullspace.num_rows() == 12 - rank
+            && nullspace.rank() == nullspace.num_rows()
+            && nullspace.rows().all(|row| gf2_apply(&matrix, &row.to_bit_vec()) == zero)
+            && gf2_apply(&matrix, &solution) == b
+    }
+
+    fn prop_solve_inconsistent(seed: Vec<bool>, b: Vec<bool>) -> bool {
+        let bit = |r: usize, c: u64| {
+            !seed.is_empty() && seed[(r * 7 + c as usize) % seed.len()]
+        };
+
+        let matrix: BitMatrix<u8> = tabulate(10, 4, bit);
+        let mut b0: BitVec<u8> = BitVec::new_fill(false, 10);
+        for (i, &v) in b.iter().take(10).enumerate() {
+            b0.set(i as u64, v);
+        }
+
+        // `b0` is in the column space exactly when appending it keeps the rank.
+        let augmented = BitMatrix::from_rows(10, matrix.transpose().rows().chain(Some(b0.as_slice())));
+        let consistent = augmented.rank() == matrix.rank();
+
+        match matrix.solve(&b0) {
+            Some(x) => consistent && gf2_apply(&matrix, &x) == b0,
+            None    => !consistent,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use {BlockType, Bits, BitsMut, BitsPush, BitSliceable, BitSlice, BitSliceMut};
 use super::BitVec;
-use iter::BlockIter;
+use iter::{BlockIter, BitIter};
 use storage::Address;
 
 use traits::get_masked_block;
@@ -60,6 +60,18 @@ impl<Block: BlockType> BitsMut for BitVec<Block> {
             self.bits.set_block(position, value);
         }
     }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        assert!( range.start <= range.end && range.end <= self.len(),
+                 "BitVec::set_range: out of bounds" );
+        self.as_mut_slice().set_range(range, value);
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        assert!( range.start <= range.end && range.end <= self.len(),
+                 "BitVec::toggle_range: out of bounds" );
+        self.as_mut_slice().toggle_range(range);
+    }
 }
 
 impl<Block: BlockType> BitsPush for BitVec<Block> {
@@ -100,6 +112,44 @@ impl<Block: BlockType> BitsPush for BitVec<Block> {
         let last = self.block_len() - 1;
         self.set_block(last, value);
     }
+
+    fn push_bits(&mut self, value: Block, count: usize) {
+        assert!( count <= Block::nbits(),
+                 "BitVec::push_bits: count too large" );
+        if count == 0 { return; }
+
+        self.reserve(count as u64);
+        let start = self.len;
+        self.len += count as u64;
+        // This merges `value` into at most two blocks.
+        self.set_bits(start, count, value);
+    }
+
+    fn extend_from_bits<T>(&mut self, other: T)
+        where T: Bits<Block = Block>,
+              Self: Sized {
+
+        let len         = other.bit_len();
+        let full_blocks = Block::div_nbits(len);
+        let extra_bits  = Block::mod_nbits(len);
+
+        self.reserve(len);
+
+        if Block::mod_nbits(self.len) == 0 {
+            // Already aligned, so we can copy whole blocks.
+            for i in 0 .. full_blocks {
+                self.push_block(other.get_raw_block(i));
+            }
+        } else {
+            for i in 0 .. full_blocks {
+                self.push_bits(other.get_raw_block(i), Block::nbits());
+            }
+        }
+
+        if extra_bits > 0 {
+            self.push_bits(other.get_raw_block(full_blocks), extra_bits);
+        }
+    }
 }
 
 impl<'a, Block: BlockType> BitSliceable<Range<u64>> for &'a BitVec<Block> {
@@ -206,6 +256,14 @@ impl_index_from_bits! {
     impl[Block: BlockType] Index<u64> for BitVec<Block>;
 }
 
+impl_bit_ops_from_bits! {
+    impl['a, Block: BlockType] BitOps for &'a BitVec<Block>;
+}
+
+impl_bit_assign_ops_from_bits_mut! {
+    impl[Block: BlockType] BitAssignOps for BitVec<Block>;
+}
+
 impl<Other: Bits> PartialEq<Other> for BitVec<Other::Block> {
     fn eq(&self, other: &Other) -> bool {
         BlockIter::new(self) == BlockIter::new(other)
@@ -257,3 +315,21 @@ impl<Block: BlockType> From<Vec<Block>> for BitVec<Block> {
         vec.into_boxed_slice().into()
     }
 }
+
+impl<Block: BlockType> IntoIterator for BitVec<Block> {
+    type Item = bool;
+    type IntoIter = BitIter<BitVec<Block>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitIter::new(self)
+    }
+}
+
+impl<'a, Block: BlockType> IntoIterator for &'a BitVec<Block> {
+    type Item = bool;
+    type IntoIter = BitIter<&'a BitVec<Block>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitIter::new(self)
+    }
+}
@@ -1,8 +1,10 @@
 use super::storage::*;
 use super::slice::*;
 use super::traits::*;
+use super::iter::BitIter;
 
 use std::cmp::{max, Ordering};
+use std::ops::Range;
 use std::ptr;
 
 mod inner;
@@ -580,6 +582,257 @@ impl<Block: BlockType> BitVec<Block> {
         }
     }
 
+    /// Inserts `value` at `position`, shifting all the bits after it
+    /// toward the end.
+    ///
+    /// The tail is moved a block at a time, not bit by bit.
+    ///
+    /// # Panics
+    ///
+    /// If `position > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, true ];
+    /// bv.insert(1, true);
+    /// assert_eq!( bv, bit_vec![ true, true, false, true ] );
+    /// bv.insert(4, false);
+    /// assert_eq!( bv, bit_vec![ true, true, false, true, false ] );
+    /// ```
+    pub fn insert(&mut self, position: u64, value: bool) {
+        assert!(position <= self.len, "BitVec::insert: out of bounds");
+
+        self.push(false);
+        self.as_mut_slice().bit_slice(position ..).shift_left_in_place(1, value);
+    }
+
+    /// Removes and returns the bit at `position`, shifting all the bits
+    /// after it toward the start.
+    ///
+    /// The tail is moved a block at a time, not bit by bit.
+    ///
+    /// # Panics
+    ///
+    /// If `position >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, true, true ];
+    /// assert_eq!( bv.remove(1), false );
+    /// assert_eq!( bv, bit_vec![ true, true, true ] );
+    /// ```
+    pub fn remove(&mut self, position: u64) -> bool {
+        assert!(position < self.len, "BitVec::remove: out of bounds");
+
+        let result = self.get_bit(position);
+        self.as_mut_slice().bit_slice(position ..).shift_right_in_place(1, false);
+        self.len -= 1;
+        result
+    }
+
+    /// Removes and returns the bit at `position`, replacing it with the last
+    /// bit.
+    ///
+    /// This does not preserve the order of the bits, but takes constant
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// If `position >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, true, false ];
+    /// assert_eq!( bv.swap_remove(0), true );
+    /// assert_eq!( bv, bit_vec![ false, false, true ] );
+    /// ```
+    pub fn swap_remove(&mut self, position: u64) -> bool {
+        assert!(position < self.len, "BitVec::swap_remove: out of bounds");
+
+        let result = self.get_bit(position);
+        let last   = self.pop().unwrap();
+        if position < self.len {
+            self.set_bit(position, last);
+        }
+        result
+    }
+
+    /// Removes the bits in `range`, returning them as an iterator.
+    ///
+    /// The bits after the range are moved down a block at a time. Unlike
+    /// `Vec::drain`, the removal happens eagerly, so the vector is already
+    /// shortened even if the iterator is dropped unused.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds or its start exceeds its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, false, true, true ];
+    /// let drained: Vec<bool> = bv.drain(1 .. 4).collect();
+    ///
+    /// assert_eq!( drained, vec![ false, false, true ] );
+    /// assert_eq!( bv, bit_vec![ true, true ] );
+    /// ```
+    pub fn drain(&mut self, range: Range<u64>) -> BitIter<BitVec<Block>> {
+        self.splice(range, BitVec::new())
+    }
+
+    /// Replaces the bits in `range` with the bits of `replacement`, returning
+    /// the removed bits as an iterator.
+    ///
+    /// The range and the replacement may have different lengths; the bits
+    /// after the range are moved a block at a time to make room or close the
+    /// gap. As with [`drain`](#method.drain), the replacement happens
+    /// eagerly.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds or its start exceeds its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, false, true ];
+    /// let removed: Vec<bool> =
+    ///     bv.splice(1 .. 3, bit_vec![ true, true, true ]).collect();
+    ///
+    /// assert_eq!( removed, vec![ false, false ] );
+    /// assert_eq!( bv, bit_vec![ true, true, true, true, true ] );
+    /// ```
+    pub fn splice<B>(&mut self, range: Range<u64>, replacement: B) -> BitIter<BitVec<Block>>
+        where B: Bits<Block = Block> {
+
+        assert!(range.start <= range.end && range.end <= self.len,
+                "BitVec::splice: out of bounds");
+
+        let removed     = BitVec::from_bits(self.as_slice().bit_slice(range.clone()));
+        let old_len     = self.len;
+        let removed_len = range.end - range.start;
+        let added_len   = replacement.bit_len();
+
+        if added_len > removed_len {
+            let growth = added_len - removed_len;
+            self.resize(old_len + growth, false);
+            self.as_mut_slice().bit_slice(range.end ..).shift_left_in_place(growth, false);
+        } else if added_len < removed_len {
+            let shrinkage = removed_len - added_len;
+            self.as_mut_slice().bit_slice(range.start + added_len ..)
+                .shift_right_in_place(shrinkage, false);
+            self.truncate(old_len - shrinkage);
+        }
+
+        self.as_mut_slice().bit_slice(range.start .. range.start + added_len)
+            .bit_assign(replacement);
+
+        removed.into_iter_bits()
+    }
+
+    /// Splits the bit-vector in two at `position`, returning the bits from
+    /// `position` onward as a new `BitVec`.
+    ///
+    /// # Panics
+    ///
+    /// If `position > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, false, true ];
+    /// let tail = bv.split_off(1);
+    ///
+    /// assert_eq!( bv, bit_vec![ true ] );
+    /// assert_eq!( tail, bit_vec![ false, false, true ] );
+    /// ```
+    pub fn split_off(&mut self, position: u64) -> Self {
+        assert!(position <= self.len, "BitVec::split_off: out of bounds");
+
+        let result = BitVec::from_bits(self.as_slice().bit_slice(position ..));
+        self.truncate(position);
+        result
+    }
+
+    /// Moves all the bits of `other` onto the end of `self`, leaving `other`
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv1: BitVec = bit_vec![ true, false ];
+    /// let mut bv2: BitVec = bit_vec![ false, true, true ];
+    /// bv1.append(&mut bv2);
+    ///
+    /// assert_eq!( bv1, bit_vec![ true, false, false, true, true ] );
+    /// assert!( bv2.is_empty() );
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend_from_bits(&*other);
+        other.clear();
+    }
+
+    /// Keeps only the bits at the positions set in `mask`, moving them
+    /// together in order and shortening the bit-vector. This is the
+    /// in-place version of [`BitsExt::bit_extract`].
+    ///
+    /// Works a block at a time using [`BlockType::pext`].
+    ///
+    /// # Panics
+    ///
+    /// If `self.len() != mask.bit_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ true, false, false, true, true ];
+    /// bv.retain_bits(bit_vec![ false, true, true, true, false ]);
+    /// assert_eq!( bv, bit_vec![ false, false, true ] );
+    /// ```
+    ///
+    /// [`BitsExt::bit_extract`]: trait.BitsExt.html#method.bit_extract
+    /// [`BlockType::pext`]: trait.BlockType.html#method.pext
+    pub fn retain_bits<Mask>(&mut self, mask: Mask)
+        where Mask: Bits<Block = Block> {
+
+        assert_eq!( self.len(), mask.bit_len(),
+                    "BitVec::retain_bits: arguments have different lengths" );
+
+        // The bits kept from block `i` land at or below block `i`, so they
+        // never overwrite a block that has yet to be read.
+        let mut kept = 0;
+        for i in 0 .. self.block_len() {
+            let mask_block = mask.get_block(i);
+            let count      = mask_block.count_ones();
+            if count > 0 {
+                let packed = self.get_raw_block(i).pext(mask_block);
+                self.set_bits(kept, count, packed);
+                kept += count as u64;
+            }
+        }
+
+        self.truncate(kept);
+    }
+
     /// Removes all elements from the bit-vector.
     ///
     /// Does not change the capacity.
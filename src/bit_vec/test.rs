@@ -1,4 +1,5 @@
 use super::*;
+use LengthMismatch;
 
 #[test]
 fn bit_slicing() {
@@ -188,3 +189,506 @@ fn mixed_equality() {
     let array: &[bool] = &[true, false, true];
     assert_eq!( bv, array );
 }
+
+#[test]
+fn count_ones() {
+    let mut bv: BitVec<u8> = bit_vec![true; 20];
+    bv.set_bit(3, false);
+    bv.set_bit(17, false);
+    assert_eq!( bv.count_ones(), 18 );
+    assert_eq!( bv.count_zeros(), 2 );
+    assert_eq!( bv.count_ones_in(0 .. 20), 18 );
+    assert_eq!( bv.count_ones_in(3 .. 4), 0 );
+    assert_eq!( bv.count_ones_in(2 .. 18), 14 );
+    assert_eq!( bv.count_ones_in(9 .. 9), 0 );
+    assert_eq!( bv.bit_not().count_ones(), 2 );
+    assert_eq!( bv.as_mut_slice().bit_slice(1..19).count_ones(), 16 );
+}
+
+#[test]
+#[should_panic]
+fn count_ones_in_out_of_bounds() {
+    let bv: BitVec = bit_vec![true; 4];
+    bv.count_ones_in(2 .. 5);
+}
+
+#[test]
+fn masked_blocks() {
+    let mut bv: BitVec<u8> = bit_vec![true; 12];
+    bv.set_bit(9, false);
+    assert_eq!( bv.bit_not().get_block(1), 0b0010 );
+    assert_eq!( bv.as_mut_slice().get_block(1), 0b1101 );
+}
+
+#[test]
+fn find_ones_and_zeros() {
+    let mut bv: BitVec<u8> = bit_vec![false; 20];
+    assert_eq!( bv.first_one(), None );
+    assert_eq!( bv.last_one(), None );
+    assert_eq!( bv.first_zero(), Some(0) );
+    assert_eq!( bv.last_zero(), Some(19) );
+
+    bv.set_bit(5, true);
+    bv.set_bit(13, true);
+    assert_eq!( bv.first_one(), Some(5) );
+    assert_eq!( bv.last_one(), Some(13) );
+    assert_eq!( bv.next_one(5), Some(5) );
+    assert_eq!( bv.next_one(6), Some(13) );
+    assert_eq!( bv.next_one(14), None );
+    assert_eq!( bv.next_one(20), None );
+    assert_eq!( bv.prev_one(13), Some(5) );
+    assert_eq!( bv.prev_one(5), None );
+    assert_eq!( bv.prev_one(0), None );
+
+    let slice = bv.bit_slice(5 .. 14);
+    assert_eq!( slice.first_one(), Some(0) );
+    assert_eq!( slice.last_one(), Some(8) );
+    assert_eq!( slice.next_zero(0), Some(1) );
+    assert_eq!( slice.prev_zero(8), Some(7) );
+}
+
+#[test]
+fn find_ignores_bits_past_the_end() {
+    let bv: BitVec<u8> = bit_vec![true; 12];
+    assert_eq!( bv.first_zero(), None );
+    assert_eq!( bv.last_zero(), None );
+    assert_eq!( bv.bit_not().last_one(), None );
+
+    let array = [0b11110000u8, 0b00001111];
+    let slice = array.bit_slice(4 .. 12);
+    assert_eq!( slice.first_zero(), None );
+    assert_eq!( slice.bit_slice(4 ..).first_zero(), None );
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut bv: BitVec<u8> = BitVec::new_fill(false, 20);
+    bv.set_bit(19, true);
+    bv.insert(0, true);
+    assert_eq!( bv.len(), 21 );
+    assert!( bv[0] );
+    assert!( bv[20] );
+    assert_eq!( bv.count_ones(), 2 );
+
+    bv.insert(21, true);
+    bv.insert(8, true);
+    assert_eq!( bv.ones().collect::<Vec<_>>(), vec![0, 8, 21, 22] );
+
+    assert!( bv.remove(0) );
+    assert!( !bv.remove(0) );
+    assert_eq!( bv.ones().collect::<Vec<_>>(), vec![6, 19, 20] );
+
+    assert!( bv.swap_remove(6) );
+    assert_eq!( bv.len(), 20 );
+    assert_eq!( bv.ones().collect::<Vec<_>>(), vec![6, 19] );
+    assert!( bv.swap_remove(19) );
+    assert_eq!( bv.len(), 19 );
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut bv: BitVec = bit_vec![true, false];
+    bv.insert(3, true);
+}
+
+#[test]
+#[should_panic]
+fn remove_out_of_bounds() {
+    let mut bv: BitVec = bit_vec![true, false];
+    bv.remove(2);
+}
+
+#[test]
+#[should_panic]
+fn swap_remove_out_of_bounds() {
+    let mut bv: BitVec = bit_vec![];
+    bv.swap_remove(0);
+}
+
+quickcheck! {
+    fn prop_insert_remove(bits: Vec<bool>, ops: Vec<(usize, Option<bool>)>) -> bool {
+        let mut expected = bits.clone();
+        let mut actual: BitVec<u8> = BitVec::from_bits(&bits);
+
+        for (position, op) in ops {
+            match op {
+                Some(value) => {
+                    let position = position % (expected.len() + 1);
+                    expected.insert(position, value);
+                    actual.insert(position as u64, value);
+                }
+                None if !expected.is_empty() => {
+                    let position = position % expected.len();
+                    if expected.remove(position) != actual.remove(position as u64) {
+                        return false;
+                    }
+                }
+                None => { }
+            }
+        }
+
+        actual == BitVec::<u8>::from_bits(&expected)
+    }
+}
+
+#[test]
+fn drain_and_splice() {
+    let mut bv: BitVec<u8> = BitVec::new_fill(false, 30);
+    bv.set_bit(3, true);
+    bv.set_bit(12, true);
+    bv.set_bit(29, true);
+
+    let drained = bv.drain(2 .. 13);
+    assert_eq!( drained.len(), 11 );
+    assert_eq!( bv.len(), 19 );
+    assert_eq!( bv.ones().collect::<Vec<_>>(), vec![18] );
+
+    let removed: Vec<bool> = bv.splice(0 .. 1, BitVec::<u8>::new_fill(true, 10)).collect();
+    assert_eq!( removed, vec![false] );
+    assert_eq!( bv.len(), 28 );
+    assert_eq!( bv.count_ones(), 11 );
+    assert!( bv[27] );
+
+    bv.splice(28 .. 28, bit_vec![true]);
+    assert_eq!( bv.len(), 29 );
+    assert_eq!( bv.last_one(), Some(28) );
+}
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut bv: BitVec = bit_vec![true, false];
+    bv.drain(1 .. 3);
+}
+
+#[test]
+fn split_off_and_append() {
+    let mut bv: BitVec<u8> = BitVec::new_fill(true, 20);
+    let mut tail = bv.split_off(7);
+    assert_eq!( bv.len(), 7 );
+    assert_eq!( tail.len(), 13 );
+
+    tail.set_bit(0, false);
+    bv.append(&mut tail);
+    assert_eq!( bv.len(), 20 );
+    assert_eq!( bv.zeros().collect::<Vec<_>>(), vec![7] );
+    assert!( tail.is_empty() );
+
+    let empty = bv.split_off(20);
+    assert!( empty.is_empty() );
+}
+
+quickcheck! {
+    fn prop_splice(bits: Vec<bool>, start: usize, end: usize, replacement: Vec<bool>) -> bool {
+        let len = bits.len();
+        let (start, end) = if len == 0 {
+            (0, 0)
+        } else {
+            let a = start % (len + 1);
+            let b = end % (len + 1);
+            (a.min(b), a.max(b))
+        };
+
+        let mut expected = bits.clone();
+        let expected_removed: Vec<bool> =
+            expected.splice(start .. end, replacement.iter().cloned()).collect();
+
+        let mut actual: BitVec<u8> = BitVec::from_bits(&bits);
+        let actual_removed: Vec<bool> =
+            actual.splice(start as u64 .. end as u64, &replacement).collect();
+
+        actual_removed == expected_removed
+            && actual == BitVec::<u8>::from_bits(&expected)
+    }
+}
+
+#[test]
+fn push_bits_unaligned() {
+    let mut bv: BitVec<u16> = bit_vec![true, false, true];
+    bv.push_bits(0b1_0110_0111_0101, 13);
+    assert_eq!( bv.len(), 16 );
+    assert_eq!( bv.get_bits(3, 13), 0b1_0110_0111_0101 );
+    assert_eq!( bv.get_bits(0, 3), 0b101 );
+
+    bv.push_bits(0xFFFF, 0);
+    assert_eq!( bv.len(), 16 );
+}
+
+#[test]
+#[should_panic]
+fn push_bits_too_many() {
+    let mut bv: BitVec<u8> = BitVec::new();
+    bv.push_bits(0, 9);
+}
+
+quickcheck! {
+    fn prop_extend_from_bits(bits1: Vec<bool>, bits2: Vec<bool>, start: usize) -> bool {
+        let start = if bits2.is_empty() {0} else {start % bits2.len()};
+
+        let mut expected = bits1.clone();
+        expected.extend_from_slice(&bits2[start ..]);
+
+        let source: BitVec<u8> = BitVec::from_bits(&bits2);
+        let mut actual: BitVec<u8> = BitVec::new();
+        for &bit in &bits1 { actual.push(bit); }
+        actual.extend_from_bits(source.bit_slice(start as u64 ..));
+
+        let mut by_default = bits1.clone();
+        by_default.extend_from_bits(&bits2[start ..]);
+
+        actual.len() == expected.len() as u64
+            && (0 .. expected.len()).all(|i| actual[i as u64] == expected[i])
+            && by_default == expected
+    }
+}
+
+#[test]
+fn set_and_toggle_range() {
+    let mut bv: BitVec<u8> = BitVec::new_fill(false, 20);
+    bv.set_range(3 .. 18, true);
+    assert_eq!( bv.count_ones(), 15 );
+    assert_eq!( bv.first_one(), Some(3) );
+    assert_eq!( bv.last_one(), Some(17) );
+
+    bv.clear_range(8 .. 16);
+    assert_eq!( bv.get_bits(0, 8), 0b1111_1000 );
+    assert_eq!( bv.get_bits(8, 8), 0 );
+
+    bv.toggle_range(0 .. 20);
+    assert_eq!( bv.get_bits(0, 8), 0b0000_0111 );
+    assert_eq!( bv.get_bits(8, 8), 0xFF );
+    assert_eq!( bv.get_bits(16, 4), 0b1100 );
+
+    bv.set_range(5 .. 5, true);
+    assert_eq!( bv.get_bits(0, 8), 0b0000_0111 );
+}
+
+#[test]
+#[should_panic]
+fn set_range_out_of_bounds() {
+    let mut bv: BitVec<u8> = BitVec::new_fill(false, 20);
+    bv.set_range(3 .. 21, true);
+}
+
+quickcheck! {
+    fn prop_set_and_toggle_range(bits: Vec<bool>, offset: u8, start: u64, end: u64,
+                                 value: bool) -> bool {
+        let len    = bits.len() as u64;
+        let offset = u64::from(offset % 8);
+        let (start, end) = if len == 0 {
+            (0, 0)
+        } else {
+            let a = start % (len + 1);
+            let b = end % (len + 1);
+            (a.min(b), a.max(b))
+        };
+
+        let mut expected = bits.clone();
+        for bit in &mut expected[start as usize .. end as usize] { *bit = value; }
+        let mut toggled = expected.clone();
+        for bit in &mut toggled[.. start as usize] { *bit = !*bit; }
+
+        // `Vec<bool>` uses the default methods.
+        let mut by_default = bits.clone();
+        by_default.set_range(start .. end, value);
+
+        let mut bv: BitVec<u8> = BitVec::from_bits(&bits);
+        bv.set_range(start .. end, value);
+
+        let mut blocks = bv.clone().into_boxed_slice();
+        blocks.toggle_range(0 .. start);
+
+        let mut padded: BitVec<u8> = BitVec::new_fill(true, offset);
+        padded.extend_from_bits(&bits);
+        padded.as_mut_slice().bit_slice(offset ..).set_range(start .. end, value);
+        padded.as_mut_slice().bit_slice(offset ..).toggle_range(0 .. start);
+
+        by_default == expected
+            && bv == BitVec::<u8>::from_bits(&expected)
+            && (&blocks[..]).bit_slice(.. len) == BitVec::<u8>::from_bits(&toggled)
+            && padded.bit_slice(.. offset).count_zeros() == 0
+            && padded.bit_slice(offset ..) == BitVec::<u8>::from_bits(&toggled)
+    }
+}
+
+#[test]
+fn extract_and_deposit() {
+    let bv: BitVec<u8> = BitVec::from_bits(&[0b1011_0110u8, 0b0101]);
+    let mask: BitVec<u8> = BitVec::from_bits(&[0b1111_0000u8, 0b0011]);
+
+    let packed = bv.bit_extract(&mask);
+    assert_eq!( packed.len(), 6 );
+    assert_eq!( packed.get_bits(0, 6), 0b01_1011 );
+
+    let unpacked = packed.bit_deposit(&mask, 16);
+    assert_eq!( unpacked.get_bits(0, 8), 0b1011_0000 );
+    assert_eq!( unpacked.get_bits(8, 8), 0b0001 );
+
+    let mut retained = bv.clone();
+    retained.retain_bits(&mask);
+    assert_eq!( retained, packed );
+}
+
+#[test]
+#[should_panic]
+fn deposit_not_enough_bits() {
+    let bv: BitVec<u8> = bit_vec![true];
+    bv.bit_deposit(&[0b11u8], 8);
+}
+
+quickcheck! {
+    fn prop_extract_deposit_retain(bits: Vec<bool>, mask: Vec<bool>) -> bool {
+        let len  = bits.len().min(mask.len());
+        let bits = &bits[.. len];
+        let mask = &mask[.. len];
+
+        let expected: Vec<bool> = (0 .. len).filter(|&i| mask[i]).map(|i| bits[i]).collect();
+        let deposited: Vec<bool> = (0 .. len).map(|i| mask[i] && bits[i]).collect();
+
+        let bv: BitVec<u8> = BitVec::from_bits(bits);
+        let mask_bv: BitVec<u8> = BitVec::from_bits(mask);
+
+        let extracted = bv.bit_extract(&mask_bv);
+        let mut retained = bv.clone();
+        retained.retain_bits(&mask_bv);
+
+        extracted == BitVec::<u8>::from_bits(&expected)
+            && retained == extracted
+            && extracted.bit_deposit(&mask_bv, len as u64)
+                == BitVec::<u8>::from_bits(&deposited)
+    }
+}
+
+#[test]
+fn add_carries_across_blocks() {
+    let ones: BitVec<u8> = BitVec::new_fill(true, 20);
+    let mut one: BitVec<u8> = BitVec::new_fill(false, 20);
+    one.set(0, true);
+
+    assert_eq!( ones.bit_add(&one), BitVec::<u8>::new_fill(false, 20) );
+    assert_eq!( one.bit_sub(&ones).count_ones(), 1 );
+    assert_eq!( one.bit_add(&one).bit_slice(1 ..).first_one(), Some(0) );
+
+    let mut sum = ones.clone();
+    assert!( sum.bit_add_assign(&one) );
+    assert_eq!( sum.count_ones(), 0 );
+    assert!( sum.bit_sub_assign(&one) );
+    assert_eq!( sum, ones );
+    assert!( sum.bit_increment() );
+    assert!( !sum.bit_increment() );
+    assert_eq!( sum, one );
+}
+
+#[test]
+#[should_panic]
+fn add_assign_bad_sizes() {
+    let mut bv: BitVec = BitVec::new_fill(false, 3);
+    bv.bit_add_assign(&BitVec::<usize>::new_fill(false, 4));
+}
+
+quickcheck! {
+    // The operands are nearly complements, or nearly equal, so that carries
+    // and borrows propagate across many blocks, and the blocks are read in
+    // an arbitrary order so as to exercise the cached carry.
+    fn prop_arith_block_order(bits: Vec<bool>, flips: Vec<usize>, order: Vec<usize>) -> bool {
+        let bv1: BitVec<u8> = BitVec::from_bits(&bits);
+        let mut bv2 = bv1.clone();
+        if !bits.is_empty() {
+            for &i in &flips {
+                let i = (i % bits.len()) as u64;
+                let bit = bv2.get_bit(i);
+                bv2.set_bit(i, !bit);
+            }
+        }
+        let not2 = bv2.bit_not().to_bit_vec();
+
+        let sum  = bv1.bit_add(&not2);
+        let diff = bv1.bit_sub(&bv2);
+        let nblocks = bv1.block_len();
+
+        nblocks == 0 || order.iter().all(|&i| {
+            let i = i % nblocks;
+            sum.get_block(i) == bv1.bit_add(&not2).get_block(i)
+                && diff.get_block(i) == bv1.bit_sub(&bv2).get_block(i)
+        })
+    }
+}
+
+quickcheck! {
+    fn prop_arith(a: u64, b: u64, len: u8, len2: u8) -> bool {
+        let len  = u64::from(len % 65);
+        let len2 = u64::from(len2 % 65);
+        let to_bv = |x: u64, len: u64| {
+            let mut result: BitVec<u8> = BitVec::new();
+            for i in 0 .. len {
+                result.push(x >> i & 1 == 1);
+            }
+            result
+        };
+        let mask = |len: u64| if len == 64 { !0 } else { (1u64 << len) - 1 };
+
+        let (x, y)   = (a & mask(len), b & mask(len));
+        let (bv1, bv2) = (to_bv(x, len), to_bv(y, len));
+
+        let mut sum = bv1.clone();
+        let carry   = sum.bit_add_assign(&bv2);
+        let mut diff = bv1.clone();
+        let borrow   = diff.bit_sub_assign(&bv2);
+        let mut succ = bv1.clone();
+        let wrapped  = succ.bit_increment();
+
+        let other = b & mask(len2);
+
+        bv1.bit_add(&bv2) == to_bv(x.wrapping_add(y) & mask(len), len)
+            && bv1.bit_sub(&bv2) == to_bv(x.wrapping_sub(y) & mask(len), len)
+            && sum == bv1.bit_add(&bv2)
+            && carry == (x > mask(len) - y)
+            && diff == bv1.bit_sub(&bv2)
+            && borrow == (x < y)
+            && succ == to_bv(x.wrapping_add(1) & mask(len), len)
+            && wrapped == (x == mask(len))
+            && bv1.numeric_cmp(&to_bv(other, len2)) == x.cmp(&other)
+    }
+}
+
+#[test]
+fn operators() {
+    let a: BitVec<u8> = bit_vec![false, false, true, true, false, true, true, false, true];
+    let b: BitVec<u8> = bit_vec![false, true, false, true, true, true, false, false, true];
+    let c: BitVec<u8> = bit_vec![true, false, false, false, false, false, false, false, true];
+
+    assert_eq!( &a & &b | &c, a.bit_and(&b).into_bit_or(&c) );
+    assert_eq!( !(&a ^ &b), a.bit_xor(&b).into_bit_not() );
+    assert_eq!( a.as_slice() & &b, a.bit_and(&b) );
+    assert_eq!( !a.bit_slice(1 .. 4), bit_vec![true, false, false] );
+    assert_eq!( a.bit_shl(1) ^ &b, a.bit_shl(1).into_bit_xor(&b) );
+
+    let mut d = a.clone();
+    d &= &b;
+    d |= !&c;
+    d ^= b.as_slice();
+    assert_eq!( d, (&a & &b | !&c) ^ &b );
+
+    let mut e = a.clone();
+    {
+        let mut tail = e.as_mut_slice().bit_slice(3 ..);
+        tail ^= c.bit_slice(3 ..);
+        tail &= &b.bit_slice(.. 6);
+    }
+    assert_eq!( e.bit_slice(.. 3), a.bit_slice(.. 3) );
+    assert_eq!( e.bit_slice(3 ..), (a.bit_slice(3 ..) ^ c.bit_slice(3 ..)) & b.bit_slice(.. 6) );
+}
+
+#[test]
+#[should_panic]
+fn operator_assign_bad_sizes() {
+    let mut bv: BitVec = BitVec::new_fill(false, 3);
+    bv |= BitVec::<usize>::new_fill(true, 4);
+}
+
+#[test]
+fn length_mismatch_display() {
+    let err = LengthMismatch { left: 3, right: 5 };
+    assert_eq!( err.to_string(), "operands have different lengths (3 and 5)" );
+}
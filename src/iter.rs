@@ -1,11 +1,26 @@
+//! Iterators over bit-vector-likes.
+//!
+//! The iterator types in this module are returned by methods of
+//! [`BitsExt`] and [`BitSlice`], and by the `IntoIterator` impls of
+//! [`BitVec`] and [`BitSlice`].
+//!
+//! [`BitsExt`]: ../trait.BitsExt.html
+//! [`BitVec`]: ../struct.BitVec.html
+//! [`BitSlice`]: ../struct.BitSlice.html
+
 use Bits;
 use BlockType;
+use adapter::BitNot;
 
 use std::cmp::Ordering;
+use std::fmt;
+
+pub use slice::{Chunks, ChunksMut};
+pub use bit_matrix::Rows;
 
 /// An iterator over the blocks of a bit-vector-like.
 #[derive(Clone, Debug)]
-pub struct BlockIter<T> {
+pub (crate) struct BlockIter<T> {
     bits: T,
     pos:  usize,
 }
@@ -13,12 +28,12 @@ pub struct BlockIter<T> {
 
 impl<T: Bits> BlockIter<T> {
     /// Creates a new block iterator from a `Bits` instance.
-    pub fn new(bits: T) -> Self {
+    pub (crate) fn new(bits: T) -> Self {
         BlockIter { bits, pos:  0, }
     }
 
     /// Returns the number of *bits* remaining in the iterator.
-    pub fn bit_len(&self) -> u64 {
+    pub (crate) fn bit_len(&self) -> u64 {
         self.bits.bit_len() - T::Block::mul_nbits(self.pos)
     }
 }
@@ -93,9 +108,294 @@ impl<T: Bits> Ord for BlockIter<T> {
     }
 }
 
+/// An iterator over the bits of a bit-vector-like.
+///
+/// This is the result of
+/// [`BitsExt::iter_bits`](../trait.BitsExt.html#method.iter_bits). It reads the
+/// underlying bits one block at a time, from either end.
+#[derive(Debug)]
+pub struct BitIter<T: Bits> {
+    bits:        T,
+    front:       u64,
+    back:        u64,
+    front_block: T::Block,
+    back_block:  T::Block,
+}
+// Invariants:
+//   front <= back <= bits.bit_len()
+//   if front is not block-aligned then front_block holds the block containing
+//     bit front
+//   if back is not block-aligned then back_block holds the block containing
+//     bit back - 1
+
+impl<T: Bits> BitIter<T> {
+    pub (crate) fn new(bits: T) -> Self {
+        let back = bits.bit_len();
+
+        // If the length isn't block-aligned then we need to load the last
+        // block up front in order to establish the invariant.
+        let back_block = if T::Block::mod_nbits(back) != 0 {
+            bits.get_raw_block(bits.block_len() - 1)
+        } else {
+            T::Block::zero()
+        };
+
+        BitIter {
+            bits,
+            front:       0,
+            back,
+            front_block: T::Block::zero(),
+            back_block,
+        }
+    }
+}
+
+impl<T: Bits + Clone> Clone for BitIter<T> {
+    fn clone(&self) -> Self {
+        BitIter {
+            bits:        self.bits.clone(),
+            front:       self.front,
+            back:        self.back,
+            front_block: self.front_block,
+            back_block:  self.back_block,
+        }
+    }
+}
+
+impl<T: Bits> Iterator for BitIter<T> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let offset = T::Block::mod_nbits(self.front);
+        if offset == 0 {
+            let position = T::Block::div_nbits(self.front);
+            self.front_block = self.bits.get_raw_block(position);
+        }
+
+        self.front += 1;
+        Some(self.front_block.get_bit(offset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Bits> DoubleEndedIterator for BitIter<T> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        let offset = T::Block::mod_nbits(self.back);
+        if offset == T::Block::nbits() - 1 {
+            let position = T::Block::div_nbits(self.back);
+            self.back_block = self.bits.get_raw_block(position);
+        }
+
+        Some(self.back_block.get_bit(offset))
+    }
+}
+
+impl<T: Bits> ExactSizeIterator for BitIter<T> {
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
+
+// Yields the positions of the set bits of `bits` in a range, a block at a
+// time. Shared by `Ones`, `Zeros` and the `Bits` search methods.
+#[derive(Clone, Debug)]
+pub (crate) struct Positions<T: Bits> {
+    bits:        T,
+    front_index: usize,
+    back_index:  usize,
+    front_block: T::Block,
+    back_block:  T::Block,
+}
+// Invariants:
+//   front_index <= back_index
+//   front_block holds the not-yet-yielded set bits of block front_index
+//   if front_index < back_index then back_block holds the not-yet-yielded
+//     set bits of block back_index; otherwise the two ends share front_block
+//   bits outside the requested range are already cleared in both blocks
+
+impl<T: Bits> Positions<T> {
+    pub (crate) fn new(bits: T, start: u64, end: u64) -> Self {
+        debug_assert!(start <= end && end <= bits.bit_len());
+
+        if start == end {
+            return Positions {
+                bits,
+                front_index: 0,
+                back_index:  0,
+                front_block: T::Block::zero(),
+                back_block:  T::Block::zero(),
+            };
+        }
+
+        let front_index = T::Block::div_nbits(start);
+        let back_index  = T::Block::div_nbits(end - 1);
+
+        let start_mask  = !T::Block::low_mask(T::Block::mod_nbits(start));
+        let end_mask    = T::Block::low_mask(T::Block::block_bits(end, back_index));
+
+        let mut front_block = bits.get_raw_block(front_index) & start_mask;
+        let back_block;
+        if front_index == back_index {
+            front_block = front_block & end_mask;
+            back_block  = T::Block::zero();
+        } else {
+            back_block  = bits.get_raw_block(back_index) & end_mask;
+        }
+
+        Positions { bits, front_index, back_index, front_block, back_block }
+    }
+}
+
+impl<T: Bits> Iterator for Positions<T> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let block = self.front_block;
+            if block != T::Block::zero() {
+                self.front_block = block & block.wrapping_sub(T::Block::one());
+                let offset = block.trailing_zeros() as u64;
+                return Some(T::Block::mul_nbits(self.front_index) + offset);
+            }
+
+            if self.front_index == self.back_index {
+                return None;
+            }
+
+            self.front_index += 1;
+            self.front_block = if self.front_index == self.back_index {
+                self.back_block
+            } else {
+                self.bits.get_raw_block(self.front_index)
+            };
+        }
+    }
+}
+
+impl<T: Bits> DoubleEndedIterator for Positions<T> {
+    fn next_back(&mut self) -> Option<u64> {
+        loop {
+            let shared = self.front_index == self.back_index;
+            let block  = if shared { self.front_block } else { self.back_block };
+
+            if block != T::Block::zero() {
+                let offset = T::Block::nbits() - 1 - block.leading_zeros();
+                let block  = block.with_bit(offset, false);
+                if shared {
+                    self.front_block = block;
+                } else {
+                    self.back_block = block;
+                }
+                return Some(T::Block::mul_nbits(self.back_index) + offset as u64);
+            }
+
+            if shared {
+                return None;
+            }
+
+            self.back_index -= 1;
+            if self.back_index != self.front_index {
+                self.back_block = self.bits.get_raw_block(self.back_index);
+            }
+        }
+    }
+}
+
+/// An iterator over the positions of the set bits of a bit-vector-like.
+///
+/// This is the result of [`BitsExt::ones`](../trait.BitsExt.html#method.ones)
+/// and [`BitsExt::ones_from`](../trait.BitsExt.html#method.ones_from). Empty
+/// blocks are skipped without examining their bits.
+#[derive(Clone)]
+pub struct Ones<T: Bits>(Positions<T>);
+
+impl<T: Bits> Ones<T> {
+    pub (crate) fn new(bits: T, start: u64) -> Self {
+        let len = bits.bit_len();
+        assert!(start <= len, "Ones::new: start out of bounds");
+        Ones(Positions::new(bits, start, len))
+    }
+}
+
+impl<T> fmt::Debug for Ones<T>
+    where T: Bits + fmt::Debug,
+          T::Block: fmt::Debug {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ones").field(&self.0).finish()
+    }
+}
+
+impl<T: Bits> Iterator for Ones<T> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.0.next()
+    }
+}
+
+impl<T: Bits> DoubleEndedIterator for Ones<T> {
+    fn next_back(&mut self) -> Option<u64> {
+        self.0.next_back()
+    }
+}
+
+/// An iterator over the positions of the unset bits of a bit-vector-like.
+///
+/// This is the result of [`BitsExt::zeros`](../trait.BitsExt.html#method.zeros)
+/// and [`BitsExt::zeros_from`](../trait.BitsExt.html#method.zeros_from).
+/// Full blocks are skipped without examining their bits.
+#[derive(Clone)]
+pub struct Zeros<T: Bits>(Positions<BitNot<T>>);
+
+impl<T: Bits> Zeros<T> {
+    pub (crate) fn new(bits: T, start: u64) -> Self {
+        let len = bits.bit_len();
+        assert!(start <= len, "Zeros::new: start out of bounds");
+        Zeros(Positions::new(BitNot::new(bits), start, len))
+    }
+}
+
+impl<T> fmt::Debug for Zeros<T>
+    where T: Bits + fmt::Debug,
+          T::Block: fmt::Debug {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Zeros").field(&self.0).finish()
+    }
+}
+
+impl<T: Bits> Iterator for Zeros<T> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.0.next()
+    }
+}
+
+impl<T: Bits> DoubleEndedIterator for Zeros<T> {
+    fn next_back(&mut self) -> Option<u64> {
+        self.0.next_back()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use BitVec;
+    use {BitVec, BitsExt, BitSliceable};
     use super::*;
 
     fn eq_iter<T, U>(bits1: T, bits2: U) -> bool
@@ -134,4 +434,150 @@ mod test {
         let bv2: BitVec = bit_vec![true, false, true];
         assert!( !eq_iter(&bv1, &bv2) );
     }
+
+    #[test]
+    fn iter_bits_empty() {
+        let bv: BitVec = bit_vec![];
+        assert_eq!( bv.iter_bits().next(), None );
+        assert_eq!( bv.iter_bits().next_back(), None );
+    }
+
+    #[test]
+    fn iter_bits_forward() {
+        let bv: BitVec<u8> = bit_vec![true, false, true, true, false, false,
+                                      true, false, false, true, true];
+        let bits: Vec<bool> = bv.iter_bits().collect();
+        assert_eq!( bits, vec![true, false, true, true, false, false,
+                               true, false, false, true, true] );
+    }
+
+    #[test]
+    fn iter_bits_backward() {
+        let bv: BitVec<u8> = bit_vec![true, false, true, true, false, false,
+                                      true, false, false, true, true];
+        let bits: Vec<bool> = bv.iter_bits().rev().collect();
+        assert_eq!( bits, vec![true, true, false, false, true, false,
+                               false, true, true, false, true] );
+    }
+
+    #[test]
+    fn iter_bits_both_ends() {
+        let array = [0b01100101u8, 0b00000011];
+        let mut iter = array.bit_slice(1..10).into_iter_bits();
+        assert_eq!( iter.len(), 9 );
+        assert_eq!( iter.next(), Some(false) );
+        assert_eq!( iter.next_back(), Some(true) );
+        assert_eq!( iter.next_back(), Some(true) );
+        assert_eq!( iter.next(), Some(true) );
+        assert_eq!( iter.len(), 5 );
+        let rest: Vec<bool> = iter.collect();
+        assert_eq!( rest, vec![false, false, true, true, false] );
+    }
+
+    #[test]
+    fn iter_bits_adapter() {
+        let bv1: BitVec = bit_vec![true, true, false, false];
+        let bv2: BitVec = bit_vec![true, false, true, false];
+        let bits: Vec<bool> = bv1.bit_xor(&bv2).iter_bits().collect();
+        assert_eq!( bits, vec![false, true, true, false] );
+    }
+
+    #[test]
+    fn for_loop() {
+        let bv: BitVec = bit_vec![true, false, true];
+        let mut count = 0;
+        for bit in &bv {
+            if bit { count += 1; }
+        }
+        assert_eq!( count, 2 );
+
+        let bits: Vec<bool> = bv.as_slice().into_iter().collect();
+        assert_eq!( bits, vec![true, false, true] );
+    }
+
+    #[test]
+    fn ones_and_zeros() {
+        let bv: BitVec<u8> = bit_vec![false, true, true, false, false, false,
+                                      false, false, false, false, true, false];
+        assert_eq!( bv.ones().collect::<Vec<_>>(), vec![1, 2, 10] );
+        assert_eq!( bv.ones().rev().collect::<Vec<_>>(), vec![10, 2, 1] );
+        assert_eq!( bv.ones_from(2).collect::<Vec<_>>(), vec![2, 10] );
+        assert_eq!( bv.ones_from(11).next(), None );
+        assert_eq!( bv.ones_from(12).next(), None );
+        assert_eq!( bv.zeros().collect::<Vec<_>>(),
+                    vec![0, 3, 4, 5, 6, 7, 8, 9, 11] );
+        assert_eq!( bv.zeros_from(9).rev().collect::<Vec<_>>(), vec![11, 9] );
+    }
+
+    #[test]
+    fn ones_both_ends() {
+        let bv: BitVec<u8> = bit_vec![true, false, true, false, true, false,
+                                      true, false, true, true];
+        let mut iter = bv.ones();
+        assert_eq!( iter.next(), Some(0) );
+        assert_eq!( iter.next_back(), Some(9) );
+        assert_eq!( iter.next_back(), Some(8) );
+        assert_eq!( iter.next_back(), Some(6) );
+        assert_eq!( iter.next(), Some(2) );
+        assert_eq!( iter.next(), Some(4) );
+        assert_eq!( iter.next(), None );
+        assert_eq!( iter.next_back(), None );
+    }
+
+    #[test]
+    #[should_panic]
+    fn ones_from_out_of_bounds() {
+        let bv: BitVec = bit_vec![true];
+        bv.ones_from(2);
+    }
+
+    quickcheck! {
+        fn prop_iter_bits(bits: Vec<bool>, start: usize) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let start = if bits.is_empty() {0} else {start % bits.len()};
+            let slice = bv.bit_slice(start as u64 ..);
+            slice.iter_bits().collect::<Vec<_>>() == bits[start ..]
+        }
+
+        fn prop_iter_bits_rev(bits: Vec<bool>) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let mut expected = bits.clone();
+            expected.reverse();
+            bv.iter_bits().rev().collect::<Vec<_>>() == expected
+        }
+
+        fn prop_ones(bits: Vec<bool>, start: usize) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let start = if bits.is_empty() {0} else {start % bits.len()};
+            let expected: Vec<u64> = (start .. bits.len())
+                .filter(|&i| bits[i]).map(|i| i as u64).collect();
+            bv.ones_from(start as u64).collect::<Vec<_>>() == expected
+        }
+
+        fn prop_zeros_rev(bits: Vec<bool>) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let expected: Vec<u64> = (0 .. bits.len()).rev()
+                .filter(|&i| !bits[i]).map(|i| i as u64).collect();
+            bv.zeros().rev().collect::<Vec<_>>() == expected
+        }
+
+        fn prop_ones_both_ends(bits: Vec<bool>, front: Vec<bool>) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let mut expected: Vec<u64> = (0 .. bits.len())
+                .filter(|&i| bits[i]).map(|i| i as u64).collect();
+            let mut iter = bv.ones();
+            for from_front in front {
+                let actual = if from_front { iter.next() } else { iter.next_back() };
+                let wanted = if expected.is_empty() {
+                    None
+                } else if from_front {
+                    Some(expected.remove(0))
+                } else {
+                    expected.pop()
+                };
+                if actual != wanted { return false; }
+            }
+            iter.collect::<Vec<_>>() == expected
+        }
+    }
 }
@@ -14,7 +14,11 @@
 //!
 //! Additionally, the [`BitsExt`] trait provides adapter methods including
 //! bit-wise logic and concatenation. These adapters work for all types that implement
-//! [`Bits`].
+//! [`Bits`]. For succinct data structures, [`RankSelect`] indexes any
+//! [`Bits`] for fast rank and select queries. [`BitMatrix`] stores a
+//! two-dimensional matrix of bits by rows. With Rust 1.63 or newer, the
+//! [`par`] module runs bulk operations over large bit vectors on multiple
+//! threads.
 //!
 //! # Examples
 //!
@@ -96,6 +100,9 @@
 //! [`BitSliceMut`]: struct.BitSliceMut.html
 //! [`BitsExt`]: trait.BitsExt.html
 //! [`adapter`]: adapter/index.html
+//! [`RankSelect`]: struct.RankSelect.html
+//! [`BitMatrix`]: struct.BitMatrix.html
+//! [`par`]: par/index.html
 
 #![warn(missing_docs)]
 
@@ -125,8 +132,20 @@ pub use self::slice::{BitSlice, BitSliceMut};
 mod bit_vec;
 pub use self::bit_vec::BitVec;
 
+mod policy;
+pub use self::policy::{LengthPolicy, LengthMismatch};
+
+mod rank_select;
+pub use self::rank_select::RankSelect;
+
+mod bit_matrix;
+pub use self::bit_matrix::BitMatrix;
+
 mod array_n_impls;
-mod iter;
 mod prims;
 
 pub mod adapter;
+pub mod iter;
+
+#[cfg(all(atomic_u8, thread_scope))]
+pub mod par;
@@ -83,3 +83,89 @@ macro_rules! impl_index_from_bits {
     };
 }
 
+
+// Implements the operators `&`, `|`, `^` and `!` for any type that
+// implements Bits, returning the corresponding lazy adapters.
+macro_rules! impl_bit_ops_from_bits {
+    (
+    $(
+        impl[ $($param:tt)* ] BitOps for $bv:ty ;
+    )+
+    )=> {
+        $(
+            impl<$($param)*, Rhs> ::std::ops::BitAnd<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                type Output = $crate::adapter::BitAnd<$bv, Rhs>;
+
+                fn bitand(self, rhs: Rhs) -> Self::Output {
+                    $crate::adapter::BitAnd::new(self, rhs)
+                }
+            }
+
+            impl<$($param)*, Rhs> ::std::ops::BitOr<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                type Output = $crate::adapter::BitOr<$bv, Rhs>;
+
+                fn bitor(self, rhs: Rhs) -> Self::Output {
+                    $crate::adapter::BitOr::new(self, rhs)
+                }
+            }
+
+            impl<$($param)*, Rhs> ::std::ops::BitXor<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                type Output = $crate::adapter::BitXor<$bv, Rhs>;
+
+                fn bitxor(self, rhs: Rhs) -> Self::Output {
+                    $crate::adapter::BitXor::new(self, rhs)
+                }
+            }
+
+            impl<$($param)*> ::std::ops::Not for $bv {
+                type Output = $crate::adapter::BitNot<$bv>;
+
+                fn not(self) -> Self::Output {
+                    $crate::adapter::BitNot::new(self)
+                }
+            }
+        )+
+    };
+}
+
+// Implements the operators `&=`, `|=` and `^=` for any type that
+// implements BitsMut, using the methods of BitsMutExt.
+macro_rules! impl_bit_assign_ops_from_bits_mut {
+    (
+    $(
+        impl[ $($param:tt)* ] BitAssignOps for $bv:ty ;
+    )+
+    )=> {
+        $(
+            impl<$($param)*, Rhs> ::std::ops::BitAndAssign<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                fn bitand_assign(&mut self, rhs: Rhs) {
+                    $crate::BitsMutExt::bit_and_assign(self, rhs);
+                }
+            }
+
+            impl<$($param)*, Rhs> ::std::ops::BitOrAssign<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                fn bitor_assign(&mut self, rhs: Rhs) {
+                    $crate::BitsMutExt::bit_or_assign(self, rhs);
+                }
+            }
+
+            impl<$($param)*, Rhs> ::std::ops::BitXorAssign<Rhs> for $bv
+                where Rhs: $crate::Bits<Block = <$bv as $crate::Bits>::Block> {
+
+                fn bitxor_assign(&mut self, rhs: Rhs) {
+                    $crate::BitsMutExt::bit_xor_assign(self, rhs);
+                }
+            }
+        )+
+    };
+}
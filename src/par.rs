@@ -0,0 +1,253 @@
+//! Multi-threaded versions of bulk operations.
+//!
+//! Each function divides its bits into `threads` runs of whole blocks and
+//! processes the runs in parallel, one on the calling thread and the rest
+//! on scoped threads. The runs start on block boundaries of the bits being
+//! processed, so when the destination is block-aligned the workers write
+//! disjoint blocks. When it is not, two neighbouring pieces of the
+//! destination may share one boundary block, which each worker writes
+//! atomically through a mask, leaving the other's bits intact.
+//!
+//! Spawning threads is only worthwhile for large bit vectors; with
+//! `threads == 1` everything happens on the calling thread.
+//!
+//! This module requires Rust 1.63 or newer, for `std::thread::scope`.
+//!
+//! # Examples
+//!
+//! ```
+//! use bv::*;
+//!
+//! let mut bv1: BitVec<u64> = BitVec::new_fill(false, 100_000);
+//! let bv2: BitVec<u64> = BitVec::new_fill(true, 100_000);
+//!
+//! bv::par::bit_zip_assign(bv1.as_mut_slice(), &bv2, |b1, b2| b1 | b2, 4);
+//! assert_eq!( bv::par::count_ones(&bv1, 4), 100_000 );
+//! ```
+
+use {Bits, BitsMut, BitVec, BitSliceMut, BlockType};
+
+use std::{cmp, panic, thread};
+use std::ops::Range;
+
+/// Counts the set bits, like [`Bits::count_ones`], using up to `threads`
+/// threads.
+///
+/// # Panics
+///
+/// If `threads == 0`.
+///
+/// [`Bits::count_ones`]: ../trait.Bits.html#method.count_ones
+pub fn count_ones<T>(bits: &T, threads: usize) -> u64
+    where T: Bits + Sync + ?Sized {
+
+    let ranges = block_ranges::<T::Block>(bits.bit_len(), threads);
+    run(ranges, |range| bits.count_ones_in(range)).into_iter().sum()
+}
+
+/// Copies the bits into a new [`BitVec`], like [`Bits::to_bit_vec`], using
+/// up to `threads` threads.
+///
+/// # Panics
+///
+/// If `threads == 0`.
+///
+/// [`BitVec`]: ../struct.BitVec.html
+/// [`Bits::to_bit_vec`]: ../trait.Bits.html#method.to_bit_vec
+pub fn to_bit_vec<T>(bits: &T, threads: usize) -> BitVec<T::Block>
+    where T: Bits + Sync + ?Sized,
+          T::Block: Send {
+
+    let mut result = BitVec::new_fill(false, bits.bit_len());
+    bit_assign(result.as_mut_slice(), bits, threads);
+    result
+}
+
+/// Assigns the bits of `src` to `dst`, like [`BitsMutExt::bit_assign`],
+/// using up to `threads` threads.
+///
+/// # Panics
+///
+/// If `dst.len() != src.bit_len()` or `threads == 0`.
+///
+/// [`BitsMutExt::bit_assign`]: ../trait.BitsMutExt.html#method.bit_assign
+pub fn bit_assign<Block, T>(dst: BitSliceMut<Block>, src: &T, threads: usize)
+    where Block: BlockType + Send,
+          T: Bits<Block = Block> + Sync + ?Sized {
+
+    assert_eq!( dst.len(), src.bit_len(),
+                "par::bit_assign: arguments have different lengths" );
+
+    run(split_blocks(dst, threads), |(first, mut piece)| {
+        for i in 0 .. piece.block_len() {
+            piece.set_block(i, src.get_raw_block(first + i));
+        }
+    });
+}
+
+/// Combines each block of `dst` with the corresponding block of `src`
+/// using `fun`, like [`BitsMutExt::bit_zip_assign`], using up to `threads`
+/// threads.
+///
+/// # Panics
+///
+/// If `dst.len() != src.bit_len()` or `threads == 0`.
+///
+/// [`BitsMutExt::bit_zip_assign`]: ../trait.BitsMutExt.html#method.bit_zip_assign
+pub fn bit_zip_assign<Block, T, F>(dst: BitSliceMut<Block>, src: &T, fun: F, threads: usize)
+    where Block: BlockType + Send,
+          T: Bits<Block = Block> + Sync + ?Sized,
+          F: Fn(Block, Block) -> Block + Sync {
+
+    assert_eq!( dst.len(), src.bit_len(),
+                "par::bit_zip_assign: arguments have different lengths" );
+
+    run(split_blocks(dst, threads), |(first, mut piece)| {
+        for i in 0 .. piece.block_len() {
+            let block = fun(piece.get_raw_block(i), src.get_raw_block(first + i));
+            piece.set_block(i, block);
+        }
+    });
+}
+
+// Divides `0 .. len` into at most `threads` ranges of nearly equal numbers
+// of blocks. Every range but the last starts and ends on a block boundary.
+fn block_ranges<Block: BlockType>(len: u64, threads: usize) -> Vec<Range<u64>> {
+    assert!(threads > 0, "par: zero threads");
+
+    let nblocks = Block::ceil_div_nbits(len);
+    let nranges = cmp::max(1, cmp::min(threads, nblocks));
+    let size    = nblocks / nranges;
+    let extra   = nblocks % nranges;
+
+    let mut start = 0;
+    (0 .. nranges).map(|i| {
+        let limit = start + size + if i < extra {1} else {0};
+        let range = Block::mul_nbits(start) .. cmp::min(Block::mul_nbits(limit), len);
+        start = limit;
+        range
+    }).collect()
+}
+
+// Splits `slice` as `block_ranges` does, pairing each piece with the index
+// of its first block.
+fn split_blocks<Block: BlockType>(slice: BitSliceMut<Block>, threads: usize)
+                                  -> Vec<(usize, BitSliceMut<Block>)> {
+
+    let mut rest   = slice;
+    let mut result = Vec::new();
+
+    for range in block_ranges::<Block>(rest.len(), threads) {
+        let (piece, tail) = rest.split_at_mut(range.end - range.start);
+        result.push((Block::div_nbits(range.start), piece));
+        rest = tail;
+    }
+
+    result
+}
+
+// Applies `work` to each input, the first on the current thread and the rest
+// on scoped threads, and returns the results in order. A panic in any
+// worker is propagated.
+fn run<I, R, F>(inputs: Vec<I>, work: F) -> Vec<R>
+    where I: Send,
+          R: Send,
+          F: Fn(I) -> R + Sync {
+
+    let work = &work;
+
+    thread::scope(|scope| {
+        let mut inputs = inputs.into_iter();
+        let first      = inputs.next();
+        let handles: Vec<_> = inputs.map(|input| scope.spawn(move || work(input)))
+                                    .collect();
+
+        let mut results = Vec::with_capacity(handles.len() + 1);
+        results.extend(first.map(work));
+
+        for handle in handles {
+            match handle.join() {
+                Ok(result)   => results.push(result),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+
+        results
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {BitsExt, BitsMutExt, BitsPush, BitSliceable};
+
+    #[test]
+    fn block_ranges_cover() {
+        assert_eq!( block_ranges::<u8>(0, 4), vec![0 .. 0] );
+        assert_eq!( block_ranges::<u8>(20, 1), vec![0 .. 20] );
+        assert_eq!( block_ranges::<u8>(20, 2), vec![0 .. 16, 16 .. 20] );
+        assert_eq!( block_ranges::<u8>(20, 8), vec![0 .. 8, 8 .. 16, 16 .. 20] );
+        assert_eq!( block_ranges::<u8>(40, 2), vec![0 .. 24, 24 .. 40] );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_threads() {
+        let bv: BitVec = BitVec::new_fill(true, 10);
+        count_ones(&bv, 0);
+    }
+
+    #[test]
+    fn large() {
+        let len = 1 << 20;
+        let mut bv1: BitVec<u32> = BitVec::new_fill(false, len + 3);
+        let mut bv2: BitVec<u32> = BitVec::new_fill(false, len + 3);
+        for i in 0 .. len / 3 {
+            bv1.set(3 * i, true);
+            bv2.set(5 * i % len, true);
+        }
+
+        assert_eq!( count_ones(&bv1, 4), bv1.count_ones() );
+        assert_eq!( to_bit_vec(&bv1.bit_xor(&bv2), 3), bv1.bit_xor(&bv2).to_bit_vec() );
+
+        let mut expected = bv1.clone();
+        expected.bit_and_assign(&bv2);
+        bit_zip_assign(bv1.as_mut_slice(), &bv2, |b1, b2| b1 & b2, 8);
+        assert_eq!( bv1, expected );
+    }
+
+    quickcheck! {
+        fn prop_count_ones(bits: Vec<bool>, threads: usize) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            count_ones(&bv, threads % 5 + 1) == bv.count_ones()
+        }
+
+        // An unaligned destination makes the workers' pieces share blocks.
+        fn prop_bit_assign(bits: Vec<bool>, offset: u8, threads: usize) -> bool {
+            let offset = u64::from(offset % 8);
+            let src: BitVec<u8> = BitVec::from_bits(&bits);
+            let mut dst: BitVec<u8> = BitVec::new_fill(true, src.len() + offset);
+
+            bit_assign(dst.as_mut_slice().bit_slice(offset ..), &src, threads % 5 + 1);
+
+            dst.bit_slice(.. offset).count_zeros() == 0
+                && dst.bit_slice(offset ..) == src
+        }
+
+        fn prop_bit_zip_assign(bits1: Vec<bool>, bits2: Vec<bool>, offset: u8,
+                               threads: usize) -> bool {
+            let len     = cmp::min(bits1.len(), bits2.len()) as u64;
+            let offset  = u64::from(offset % 8);
+            let mut bv1: BitVec<u8> = BitVec::new_fill(true, offset);
+            bv1.extend_from_bits(&bits1[.. len as usize]);
+            let bv2: BitVec<u8> = BitVec::from_bits(&bits2[.. len as usize]);
+
+            let expected = bv1.bit_slice(offset ..).bit_xor(&bv2).to_bit_vec();
+            bit_zip_assign(bv1.as_mut_slice().bit_slice(offset ..), &bv2,
+                           |b1, b2| b1 ^ b2, threads % 5 + 1);
+
+            bv1.bit_slice(.. offset).count_zeros() == 0
+                && bv1.bit_slice(offset ..) == expected
+        }
+    }
+}
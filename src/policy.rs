@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt;
+
+/// How a binary operation treats operands of different lengths.
+///
+/// This is taken by the `_with` variants of the binary operations, such as
+/// [`BitsExt::bit_and_with`] and [`BitsMutExt::bit_or_assign_with`].
+///
+/// [`BitsExt::bit_and_with`]: trait.BitsExt.html#method.bit_and_with
+/// [`BitsMutExt::bit_or_assign_with`]: trait.BitsMutExt.html#method.bit_or_assign_with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LengthPolicy {
+    /// Ignores the bits of the longer operand past the length of the
+    /// shorter, as [`BitsExt::bit_and`] and the like do.
+    ///
+    /// [`BitsExt::bit_and`]: trait.BitsExt.html#method.bit_and
+    Truncate,
+    /// Fails with [`LengthMismatch`] unless the operands have the same
+    /// length.
+    ///
+    /// [`LengthMismatch`]: struct.LengthMismatch.html
+    Strict,
+    /// Treats the shorter operand as though it were extended to the length
+    /// of the longer with copies of the given bit.
+    ExtendWith(bool),
+}
+
+/// The error returned by an operation under [`LengthPolicy::Strict`] when
+/// its operands have different lengths.
+///
+/// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// The length of the left operand.
+    pub left: u64,
+    /// The length of the right operand.
+    pub right: u64,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "operands have different lengths ({} and {})", self.left, self.right)
+    }
+}
+
+impl Error for LengthMismatch {
+    fn description(&self) -> &str {
+        "operands have different lengths"
+    }
+}
+
+impl LengthPolicy {
+    // Returns how many bits of `fill` to pad each operand with so that they
+    // meet the policy, or the error if they cannot.
+    pub (crate) fn padding(self, left: u64, right: u64)
+                           -> Result<(u64, u64, bool), LengthMismatch> {
+        match self {
+            LengthPolicy::Truncate => Ok((0, 0, false)),
+
+            LengthPolicy::Strict if left == right => Ok((0, 0, false)),
+            LengthPolicy::Strict => Err(LengthMismatch { left, right }),
+
+            LengthPolicy::ExtendWith(fill) => {
+                let len = if left < right { right } else { left };
+                Ok((len - left, len - right, fill))
+            }
+        }
+    }
+}
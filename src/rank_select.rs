@@ -0,0 +1,301 @@
+use {Bits, BitsExt};
+
+use std::cmp;
+
+// The directory counts ones at two granularities: an absolute count at the
+// start of every superblock, and a count relative to the enclosing
+// superblock at the start of every basic block. Relative counts fit in a
+// `u16` because a superblock has fewer than 2^16 bits before its last basic
+// block.
+const LG_SUPERBLOCK_BITS: u32 = 16;
+const LG_BASIC_BLOCK_BITS: u32 = 9;
+
+const BASIC_BLOCK_BITS: u64 = 1 << LG_BASIC_BLOCK_BITS;
+const BASIC_BLOCKS_PER_SUPERBLOCK: usize = 1 << (LG_SUPERBLOCK_BITS - LG_BASIC_BLOCK_BITS);
+
+/// A rank/select index over a bit-vector-like.
+///
+/// Construction makes one pass over the bits, recording how many ones
+/// precede each 65,536-bit superblock and each 512-bit basic block. Rank
+/// queries then count the ones in at most one basic block, and select
+/// queries binary search the directory before scanning a single basic
+/// block. The directory costs about 3.2% of the size of the indexed bits;
+/// see [`overhead_bits`](#method.overhead_bits).
+///
+/// The index either owns its bits or borrows them, depending on whether
+/// it is given a `BitVec` or a `&BitVec` (or any other `Bits`). The bits
+/// must not change while indexed, which the borrow checker enforces.
+///
+/// # Examples
+///
+/// ```
+/// use bv::*;
+///
+/// let bv: BitVec = bit_vec![false, true, true, false, true];
+/// let rs = RankSelect::new(&bv);
+///
+/// assert_eq!( rs.rank1(3), 2 );
+/// assert_eq!( rs.rank0(3), 1 );
+/// assert_eq!( rs.select1(2), Some(4) );
+/// assert_eq!( rs.select0(1), Some(3) );
+/// assert_eq!( rs.select1(3), None );
+/// ```
+#[derive(Clone, Debug)]
+pub struct RankSelect<B> {
+    bits:        B,
+    superblocks: Vec<u64>,
+    blocks:      Vec<u16>,
+}
+// Invariants:
+//   superblocks.len() == ceil(bits.bit_len() / 2^16) + 1, where the
+//     last entry holds the total number of ones
+//   superblocks[i] == ones in bits[.. i * 2^16]
+//   blocks.len() == ceil(bits.bit_len() / 512)
+//   blocks[j] == ones in bits[s * 2^16 .. j * 512]
+//     where s == j / 128
+
+impl<B: Bits> RankSelect<B> {
+    /// Builds the rank/select directory over `bits`.
+    pub fn new(bits: B) -> Self {
+        let len        = bits.bit_len();
+        let nblocks    = ceil_shift(len, LG_BASIC_BLOCK_BITS);
+        let nsupers    = ceil_shift(len, LG_SUPERBLOCK_BITS);
+
+        let mut superblocks = Vec::with_capacity(nsupers + 1);
+        let mut blocks      = Vec::with_capacity(nblocks);
+        let mut total       = 0;
+        let mut relative    = 0;
+
+        for i in 0 .. nblocks {
+            if i % BASIC_BLOCKS_PER_SUPERBLOCK == 0 {
+                total += relative;
+                relative = 0;
+                superblocks.push(total);
+            }
+
+            blocks.push(relative as u16);
+
+            let start = i as u64 * BASIC_BLOCK_BITS;
+            let limit = cmp::min(start + BASIC_BLOCK_BITS, len);
+            relative += bits.count_ones_in(start .. limit);
+        }
+
+        superblocks.push(total + relative);
+
+        RankSelect { bits, superblocks, blocks }
+    }
+
+    /// The indexed bits.
+    pub fn bits(&self) -> &B {
+        &self.bits
+    }
+
+    /// Discards the directory, returning the indexed bits.
+    pub fn into_inner(self) -> B {
+        self.bits
+    }
+
+    /// The length of the indexed bits.
+    pub fn bit_len(&self) -> u64 {
+        self.bits.bit_len()
+    }
+
+    /// The total number of set bits.
+    pub fn count_ones(&self) -> u64 {
+        *self.superblocks.last().unwrap()
+    }
+
+    /// The total number of unset bits.
+    pub fn count_zeros(&self) -> u64 {
+        self.bit_len() - self.count_ones()
+    }
+
+    /// The number of bits used by the directory, not counting the indexed
+    /// bits themselves.
+    pub fn overhead_bits(&self) -> u64 {
+        64 * self.superblocks.len() as u64 + 16 * self.blocks.len() as u64
+    }
+
+    /// The number of set bits before `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position > self.bit_len()`.
+    pub fn rank1(&self, position: u64) -> u64 {
+        assert!(position <= self.bit_len(), "RankSelect::rank1: out of bounds");
+
+        if position == self.bit_len() {
+            return self.count_ones();
+        }
+
+        let block = (position >> LG_BASIC_BLOCK_BITS) as usize;
+        let start = block as u64 * BASIC_BLOCK_BITS;
+        self.ones_before_block(block) + self.bits.count_ones_in(start .. position)
+    }
+
+    /// The number of unset bits before `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position > self.bit_len()`.
+    pub fn rank0(&self, position: u64) -> u64 {
+        position - self.rank1(position)
+    }
+
+    /// The position of the set bit with rank `rank`, that is, the position
+    /// of the `rank + 1`st set bit; or `None` if there are not that many.
+    pub fn select1(&self, rank: u64) -> Option<u64> {
+        if rank >= self.count_ones() {
+            return None;
+        }
+
+        let block = self.find_block(rank, |rs, i| rs.ones_before_block(i));
+        let start = block as u64 * BASIC_BLOCK_BITS;
+        let skip  = rank - self.ones_before_block(block);
+        self.bits.ones_from(start).nth(skip as usize)
+    }
+
+    /// The position of the unset bit with rank `rank`, that is, the position
+    /// of the `rank + 1`st unset bit; or `None` if there are not that many.
+    pub fn select0(&self, rank: u64) -> Option<u64> {
+        if rank >= self.count_zeros() {
+            return None;
+        }
+
+        let block = self.find_block(rank, |rs, i| rs.zeros_before_block(i));
+        let start = block as u64 * BASIC_BLOCK_BITS;
+        let skip  = rank - self.zeros_before_block(block);
+        self.bits.zeros_from(start).nth(skip as usize)
+    }
+
+    fn ones_before_block(&self, block: usize) -> u64 {
+        let superblock = block / BASIC_BLOCKS_PER_SUPERBLOCK;
+        self.superblocks[superblock] + u64::from(self.blocks[block])
+    }
+
+    fn zeros_before_block(&self, block: usize) -> u64 {
+        block as u64 * BASIC_BLOCK_BITS - self.ones_before_block(block)
+    }
+
+    // Finds the last basic block whose preceding count (according to
+    // `before`, which must be monotone) is at most `rank`. First narrows
+    // down to a superblock, then to a basic block within it.
+    fn find_block<F>(&self, rank: u64, before: F) -> usize
+        where F: Fn(&Self, usize) -> u64 {
+
+        let nsupers = self.superblocks.len() - 1;
+        let superblock = last_at_most(0, nsupers, rank,
+                                      |i| before(self, i * BASIC_BLOCKS_PER_SUPERBLOCK));
+
+        let first = superblock * BASIC_BLOCKS_PER_SUPERBLOCK;
+        let limit = cmp::min(first + BASIC_BLOCKS_PER_SUPERBLOCK, self.blocks.len());
+        last_at_most(first, limit, rank, |i| before(self, i))
+    }
+}
+
+// Returns the last index `i` in `start .. limit` such that `key(i) <= target`,
+// assuming that `key` is monotone and `key(start) <= target`.
+fn last_at_most<F: Fn(usize) -> u64>(start: usize, limit: usize, target: u64, key: F) -> usize {
+    let mut lo = start;
+    let mut hi = limit;
+
+    // Invariant: key(lo) <= target, and every index in hi .. limit is too big.
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if key(mid) <= target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+// Returns `n / 2^lg`, rounded up.
+fn ceil_shift(n: u64, lg: u32) -> usize {
+    ((n + (1 << lg) - 1) >> lg) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {BitVec, BitsMut, BitSliceable};
+
+    const SUPERBLOCK_BITS: u64 = 1 << LG_SUPERBLOCK_BITS;
+
+    // The number of multiples of seven below `n`.
+    fn sevens_below(n: u64) -> u64 {
+        if n == 0 { 0 } else { (n - 1) / 7 + 1 }
+    }
+
+    #[test]
+    fn empty() {
+        let bv: BitVec = BitVec::new();
+        let rs = RankSelect::new(&bv);
+        assert_eq!( rs.rank1(0), 0 );
+        assert_eq!( rs.rank0(0), 0 );
+        assert_eq!( rs.select1(0), None );
+        assert_eq!( rs.select0(0), None );
+    }
+
+    #[test]
+    fn across_superblocks() {
+        let len = 3 * SUPERBLOCK_BITS + 1000;
+        let mut bv: BitVec<u64> = BitVec::new_fill(false, len);
+        let mut i = 0;
+        while i < len {
+            bv.set_bit(i, true);
+            i += 7;
+        }
+
+        let rs = RankSelect::new(bv);
+        assert_eq!( rs.count_ones(), sevens_below(len) );
+
+        for &position in &[0, 1, 7, 8, 511, 512, 513, SUPERBLOCK_BITS - 1,
+                           SUPERBLOCK_BITS, 2 * SUPERBLOCK_BITS + 3, len - 1, len] {
+            let expected = sevens_below(position);
+            assert_eq!( rs.rank1(position), expected );
+            assert_eq!( rs.rank0(position), position - expected );
+        }
+
+        for &rank in &[0, 1, 73, 9362, 9363, 28000, rs.count_ones() - 1] {
+            assert_eq!( rs.select1(rank), Some(7 * rank) );
+        }
+        assert_eq!( rs.select1(rs.count_ones()), None );
+
+        // The zeros in each run of seven are at offsets 1 through 6.
+        for &rank in &[0, 5, 6, 60000, rs.count_zeros() - 1] {
+            assert_eq!( rs.select0(rank), Some(rank / 6 * 7 + rank % 6 + 1) );
+        }
+        assert_eq!( rs.select0(rs.count_zeros()), None );
+
+        assert!( rs.overhead_bits() * 30 < len );
+        assert_eq!( rs.into_inner().bit_len(), len );
+    }
+
+    quickcheck! {
+        fn prop_rank_select(bits: Vec<bool>) -> bool {
+            let bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let rs = RankSelect::new(bv.bit_slice(..));
+
+            let mut ones  = 0;
+            let mut zeros = 0;
+            for (i, &bit) in bits.iter().enumerate() {
+                let i = i as u64;
+                if rs.rank1(i) != ones || rs.rank0(i) != zeros { return false; }
+                if bit {
+                    if rs.select1(ones) != Some(i) { return false; }
+                    ones += 1;
+                } else {
+                    if rs.select0(zeros) != Some(i) { return false; }
+                    zeros += 1;
+                }
+            }
+
+            rs.rank1(bits.len() as u64) == ones
+                && rs.select1(ones).is_none()
+                && rs.select0(zeros).is_none()
+        }
+    }
+}
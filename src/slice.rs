@@ -1,10 +1,10 @@
-use iter::BlockIter;
+use iter::{BlockIter, BitIter};
 use traits::{Bits, BitsMut, BitSliceable, get_masked_block};
 use storage::{Address, BlockType};
 use range_compat::*;
 
 use std::marker::PhantomData;
-use std::{cmp, fmt, hash, ptr};
+use std::{cmp, fmt, hash, mem, ptr};
 
 // This struct describes the span of a `BitSlice` or `BitSliceMut`, starting
 // with of offset of `offset` bits into the array of blocks, and including
@@ -16,7 +16,14 @@ struct SliceSpan {
     aligned_blocks: usize,
 }
 // Invariant:
-//   aligned_blocks == if offset == 0 { Block::ceil_div_nbits(len) } else { 0 }
+//   aligned_blocks == if offset == 0 { Block::div_nbits(len) } else { 0 }
+//
+// That is, only blocks that lie entirely within the span are accessed as full
+// blocks. A partial last block is always read and written through a mask, so
+// a slice never writes bits outside its span. This is what makes it sound to
+// split a `BitSliceMut` into parts that share a boundary block. Because those
+// parts may be sent to different threads, a block that the span covers only
+// partly is also accessed through the `shared` module below.
 
 // This struct describes the result of an indexing operation against a span.
 // We can give back a full, aligned block, or an arbitrary sequence of bits.
@@ -31,7 +38,7 @@ impl SliceSpan {
         SliceSpan {
             offset,
             len: bit_len,
-            aligned_blocks: if offset == 0 {Block::ceil_div_nbits(bit_len)} else {0},
+            aligned_blocks: if offset == 0 {Block::div_nbits(bit_len)} else {0},
         }
     }
 
@@ -76,18 +83,123 @@ impl SliceSpan {
             None
         }
     }
+
+    // Whether the span covers only part of the block at `index`, in which
+    // case another slice may own the rest of the block's bits.
+    fn is_partial<Block: BlockType>(&self, index: usize) -> bool {
+        let end = u64::from(self.offset) + self.len;
+        (index == 0 && self.offset != 0)
+            || (index == Block::div_nbits(end) && Block::mod_nbits(end) != 0)
+    }
+}
+
+// Access to blocks that may be shared with another slice. When `AtomicU8` is
+// available, such blocks are accessed a byte at a time using atomic
+// operations, so that a thread touching some bits of a block does not race
+// with another thread touching the others.
+#[cfg(atomic_u8)]
+mod shared {
+    use storage::BlockType;
+
+    use std::mem;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    pub unsafe fn load<Block: BlockType>(ptr: *const Block) -> Block {
+        let src        = ptr as *const AtomicU8;
+        let mut result = Block::zero();
+
+        {
+            let dst = &mut result as *mut Block as *mut u8;
+            for i in 0 .. mem::size_of::<Block>() as isize {
+                *dst.offset(i) = (*src.offset(i)).load(Ordering::Relaxed);
+            }
+        }
+
+        result
+    }
+
+    // Replaces the `count` bits starting at `start` with the low bits of
+    // `value`. Only the bytes that hold those bits are touched, and only
+    // bytes holding other bits as well need a read-modify-write.
+    pub unsafe fn store_bits<Block: BlockType>(ptr: *mut Block, start: usize,
+                                               count: usize, value: Block) {
+        let dst   = ptr as *const AtomicU8;
+        let mask  = Block::zero().with_bits(start, count, !Block::zero());
+        let bits  = Block::zero().with_bits(start, count, value);
+
+        let mask_bytes = &mask as *const Block as *const u8;
+        let bits_bytes = &bits as *const Block as *const u8;
+
+        for i in 0 .. mem::size_of::<Block>() as isize {
+            let byte_mask = *mask_bytes.offset(i);
+            let byte_bits = *bits_bytes.offset(i);
+            let byte      = &*dst.offset(i);
+
+            if byte_mask == !0 {
+                byte.store(byte_bits, Ordering::Relaxed);
+            } else if byte_mask != 0 {
+                byte.fetch_and(!byte_mask, Ordering::Relaxed);
+                byte.fetch_or(byte_bits, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+#[cfg(not(atomic_u8))]
+mod shared {
+    use storage::BlockType;
+
+    use std::ptr;
+
+    pub unsafe fn load<Block: BlockType>(ptr: *const Block) -> Block {
+        ptr::read(ptr)
+    }
+
+    pub unsafe fn store_bits<Block: BlockType>(ptr: *mut Block, start: usize,
+                                               count: usize, value: Block) {
+        let old_block = ptr::read(ptr);
+        ptr::write(ptr, old_block.with_bits(start, count, value));
+    }
+}
+
+// Reads the block at `index`.
+//
+// Precondition: `index` is within `span`.
+unsafe fn load_block<Block: BlockType>(bits: *const Block, span: &SliceSpan,
+                                       index: usize) -> Block {
+    let ptr = bits.offset(index as isize);
+    if span.is_partial::<Block>(index) {
+        shared::load(ptr)
+    } else {
+        ptr::read(ptr)
+    }
+}
+
+// Replaces `count` bits starting at `start` in the block at `index` with the
+// low bits of `value`.
+//
+// Precondition: the bits are within `span`.
+unsafe fn store_bits<Block: BlockType>(bits: *mut Block, span: &SliceSpan, index: usize,
+                                       start: usize, count: usize, value: Block) {
+    let ptr = bits.offset(index as isize);
+    if span.is_partial::<Block>(index) {
+        shared::store_bits(ptr, start, count, value);
+    } else {
+        let old_block = ptr::read(ptr);
+        ptr::write(ptr, old_block.with_bits(start, count, value));
+    }
 }
 
 impl BlockAddress {
-    unsafe fn read<Block: BlockType>(self, bits: *const Block) -> Block {
+    unsafe fn read<Block: BlockType>(self, span: &SliceSpan, bits: *const Block) -> Block {
         match self {
             BlockAddress::FullBlockAt(position) =>
                 ptr::read(bits.offset(position as isize)),
 
             BlockAddress::SomeBitsAt(address, count) => {
                 let offset      = address.bit_offset;
-                let ptr1        = bits.offset(address.block_index as isize);
-                let block1      = ptr::read(ptr1);
+                let index1      = address.block_index;
+                let block1      = load_block(bits, span, index1);
 
                 // Otherwise, our access is unaligned and may span two blocks. So we need
                 // to get our bits starting at `offset` in `block1`, and the rest from `block2`
@@ -107,21 +219,21 @@ impl BlockAddress {
 
                 // Otherwise, we need to get `block2` and combine bits from each block to get
                 // the result.
-                let block2      = ptr::read(ptr1.offset(1));
+                let block2      = load_block(bits, span, index1 + 1);
                 let chunk2      = block2.get_bits(0, bits_size2);
                 (chunk1 | (chunk2 << shift2))
             }
         }
     }
 
-    unsafe fn write<Block: BlockType>(self, bits: *mut Block, value: Block) {
+    unsafe fn write<Block: BlockType>(self, span: &SliceSpan, bits: *mut Block, value: Block) {
         match self {
             BlockAddress::FullBlockAt(position) =>
                 ptr::write(bits.offset(position as isize), value),
 
             BlockAddress::SomeBitsAt(address, count) => {
                 let offset  = address.bit_offset;
-                let ptr1    = bits.offset(address.block_index as isize);
+                let index1  = address.block_index;
 
                 // Otherwise, our access is unaligned. In particular, we need to align
                 // the first bits of `value` with the last `Block::nbits() - offset` bits
@@ -131,20 +243,15 @@ impl BlockAddress {
                 let shift2      = Block::nbits() - shift1;
 
                 // We aren't necessarily going to keep all `shift2` bits of `value`, though,
-                // because it might exceed the `count`. In any case, we can now read the block,
-                // overwrite the correct bits, and write the block back.
+                // because it might exceed the `count`. In any case, we can now overwrite the
+                // correct bits of the block.
                 let bits_size1  = cmp::min(count, shift2);
-                let old_block1  = ptr::read(ptr1);
-                let new_block1  = old_block1.with_bits(shift1, bits_size1, value);
-                ptr::write(ptr1, new_block1);
+                store_bits(bits, span, index1, shift1, bits_size1, value);
 
                 // The remaining bits to change in `block2`. If it's zero, we finish early.
                 let bits_size2  = count - bits_size1;
                 if bits_size2 == 0 { return; }
-                let ptr2        = ptr1.offset(1);
-                let old_block2  = ptr::read(ptr2);
-                let new_block2  = old_block2.with_bits(0, bits_size2, value >> shift2);
-                ptr::write(ptr2, new_block2);
+                store_bits(bits, span, index1 + 1, 0, bits_size2, value >> shift2);
             }
         }
     }
@@ -198,6 +305,21 @@ pub struct BitSliceMut<'a, Block> {
     marker: PhantomData<&'a mut ()>,
 }
 
+// A `BitSlice` is a shared borrow of its blocks and a `BitSliceMut` is a
+// unique borrow of its bits, so they are thread-safe just as `&'a [Block]`
+// and `&'a mut [Block]` are. The one wrinkle is that slices split from the
+// same `BitSliceMut` may share a block at the boundary, but such blocks are
+// accessed atomically (see `shared` above). Without atomic bytes the impls
+// would not be sound, so they are only provided when `AtomicU8` is.
+#[cfg(atomic_u8)]
+unsafe impl<'a, Block: BlockType + Sync> Send for BitSlice<'a, Block> {}
+#[cfg(atomic_u8)]
+unsafe impl<'a, Block: BlockType + Sync> Sync for BitSlice<'a, Block> {}
+#[cfg(atomic_u8)]
+unsafe impl<'a, Block: BlockType + Send> Send for BitSliceMut<'a, Block> {}
+#[cfg(atomic_u8)]
+unsafe impl<'a, Block: BlockType + Sync> Sync for BitSliceMut<'a, Block> {}
+
 impl<'a, Block: BlockType> BitSlice<'a, Block> {
     /// Creates a `BitSlice` from an array slice of blocks.
     ///
@@ -278,6 +400,108 @@ impl<'a, Block: BlockType> BitSlice<'a, Block> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Divides the slice into two at `mid`, returning the bits before and
+    /// from `mid`.
+    ///
+    /// # Panics
+    ///
+    /// If `mid > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![ true, false, false, true ];
+    /// let (left, right) = bv.as_slice().split_at(1);
+    ///
+    /// assert_eq!( left, bit_vec![ true ] );
+    /// assert_eq!( right, bit_vec![ false, false, true ] );
+    /// ```
+    pub fn split_at(self, mid: u64) -> (Self, Self) {
+        assert!(mid <= self.len(), "BitSlice::split_at: out of bounds");
+        (self.bit_slice(.. mid), self.bit_slice(mid ..))
+    }
+
+    /// Returns an iterator over consecutive slices of `chunk_len` bits.
+    ///
+    /// The last chunk is shorter if `chunk_len` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_len == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![ true, false, false, true, true ];
+    /// let lens: Vec<u64> = bv.as_slice().chunks(2).map(|c| c.len()).collect();
+    ///
+    /// assert_eq!( lens, vec![2, 2, 1] );
+    /// ```
+    pub fn chunks(self, chunk_len: u64) -> Chunks<'a, Block> {
+        assert!(chunk_len > 0, "BitSlice::chunks: zero chunk length");
+        Chunks { rest: self, chunk_len }
+    }
+
+    /// Reinterprets the slice with a different block type, without copying,
+    /// if the memory layout permits.
+    ///
+    /// On a little-endian target, bit `i` of a block is bit `i % 8` of its
+    /// byte `i / 8`, so the same memory holds the same bits whatever the
+    /// block type. Narrowing, such as from `u64` to `u8`, then always
+    /// succeeds. Widening succeeds when the slice starts at an address
+    /// aligned for `NewBlock` and its last `NewBlock` lies within the blocks
+    /// that the slice already covers. Otherwise, and when changing block size
+    /// on a big-endian target, this returns `None`, and
+    /// [`BitsExt::with_block`] can convert a block at a time instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let blocks = [0x0123_4567_89AB_CDEFu64];
+    /// let slice = BitSlice::from_slice(&blocks).bit_slice(4 .. 36);
+    ///
+    /// # if cfg!(target_endian = "little") {
+    /// let bytes = slice.reinterpret::<u8>().unwrap();
+    /// assert_eq!( bytes, slice.with_block::<u8>() );
+    /// assert_eq!( bytes.get_block(0), 0xDE );
+    /// # }
+    /// ```
+    ///
+    /// [`BitsExt::with_block`]: trait.BitsExt.html#method.with_block
+    pub fn reinterpret<NewBlock: BlockType>(self) -> Option<BitSlice<'a, NewBlock>> {
+        let old_nbits = Block::nbits();
+        let new_nbits = NewBlock::nbits();
+
+        if new_nbits != old_nbits && cfg!(target_endian = "big") {
+            return None;
+        }
+
+        let end = u64::from(self.span.offset) + self.span.len;
+
+        if new_nbits > old_nbits {
+            let aligned = self.bits as usize & (mem::align_of::<NewBlock>() - 1) == 0;
+            let covered = Block::mul_nbits(Block::ceil_div_nbits(end));
+            let needed  = NewBlock::mul_nbits(NewBlock::ceil_div_nbits(end));
+            if !aligned || needed > covered {
+                return None;
+            }
+        }
+
+        // This is safe because the new blocks occupy the same bytes as the
+        // blocks that `self` may read, checked above when widening.
+        unsafe {
+            Some(BitSlice::from_raw_parts(self.bits as *const NewBlock,
+                                          u64::from(self.span.offset),
+                                          self.span.len))
+        }
+    }
 }
 
 impl<'a, Block: BlockType> BitSliceMut<'a, Block> {
@@ -285,7 +509,7 @@ impl<'a, Block: BlockType> BitSliceMut<'a, Block> {
     ///
     /// The size is always a multiple of `Block::nbits()`. If you want a different size,
     /// slice.
-    pub fn from_slice(blocks: &mut [Block]) -> Self {
+    pub fn from_slice(blocks: &'a mut [Block]) -> Self {
         BitSliceMut {
             bits:   blocks.as_mut_ptr(),
             span:   SliceSpan::from_block_len::<Block>(blocks.len()),
@@ -323,8 +547,79 @@ impl<'a, Block: BlockType> BitSliceMut<'a, Block> {
         self.len() == 0
     }
 
+    /// Divides the mutable slice into two at `mid`, returning the bits
+    /// before and from `mid`.
+    ///
+    /// The two parts may share a block at the boundary. This is safe, even
+    /// if the parts are sent to different threads, because a `BitSliceMut`
+    /// only ever writes to the bits of a partially covered block through a
+    /// mask, and atomically.
+    ///
+    /// # Panics
+    ///
+    /// If `mid > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec<u8> = bit_vec![ false; 6 ];
+    ///
+    /// {
+    ///     let (mut left, mut right) = bv.as_mut_slice().split_at_mut(3);
+    ///     left.set_block(0, 0xFF);
+    ///     right.set_bit(0, true);
+    /// }
+    ///
+    /// assert_eq!( bv, bit_vec![ true, true, true, true, false, false ] );
+    /// ```
+    pub fn split_at_mut(self, mid: u64) -> (Self, Self) {
+        assert!(mid <= self.len(), "BitSliceMut::split_at_mut: out of bounds");
+
+        let offset = u64::from(self.span.offset);
+        let len    = self.len();
+
+        // This is safe because the two parts cover disjoint bits of the
+        // original span.
+        unsafe {
+            (BitSliceMut::from_raw_parts(self.bits, offset, mid),
+             BitSliceMut::from_raw_parts(self.bits, offset + mid, len - mid))
+        }
+    }
+
+    /// Returns an iterator over consecutive mutable slices of `chunk_len`
+    /// bits.
+    ///
+    /// The last chunk is shorter if `chunk_len` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_len == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![ false; 7 ];
+    ///
+    /// for mut chunk in bv.as_mut_slice().chunks_mut(3) {
+    ///     chunk.set_bit(0, true);
+    /// }
+    ///
+    /// assert_eq!( bv, bit_vec![ true, false, false, true, false, false, true ] );
+    /// ```
+    pub fn chunks_mut(self, chunk_len: u64) -> ChunksMut<'a, Block> {
+        assert!(chunk_len > 0, "BitSliceMut::chunks_mut: zero chunk length");
+        ChunksMut { rest: Some(self), chunk_len }
+    }
+
     /// Converts a mutable bit slice to immutable.
-    pub fn as_bit_slice(&self) -> BitSlice<'a, Block> {
+    ///
+    /// The result borrows `self`, so the bits cannot be changed while
+    /// it is in use.
+    pub fn as_bit_slice(&self) -> BitSlice<Block> {
         BitSlice {
             bits:   self.bits,
             span:   self.span,
@@ -333,7 +628,7 @@ impl<'a, Block: BlockType> BitSliceMut<'a, Block> {
     }
 }
 
-impl<'a, 'b, Block: BlockType> From<&'b BitSliceMut<'a, Block>> for BitSlice<'a, Block> {
+impl<'a, 'b, Block: BlockType> From<&'b BitSliceMut<'a, Block>> for BitSlice<'b, Block> {
     fn from(slice: &'b BitSliceMut<'a, Block>) -> Self {
         slice.as_bit_slice()
     }
@@ -354,21 +649,51 @@ impl<'a, Block: BlockType> From<&'a mut [Block]> for BitSliceMut<'a, Block> {
 // Gets `bits[offset + position]`.
 //
 // Precondition: Bits are in bounds.
-unsafe fn get_raw_bit<Block: BlockType>(bits: *const Block, address: Address) -> bool {
+unsafe fn get_raw_bit<Block: BlockType>(bits: *const Block, span: &SliceSpan,
+                                        address: Address) -> bool {
 
-    let ptr       = bits.offset(address.block_index as isize);
-    let block     = ptr::read(ptr);
+    let block     = load_block(bits, span, address.block_index);
     block.get_bit(address.bit_offset)
 }
 
 // Sets `bits[offset + position]`
 //
 // Precondition: Bit is in bounds.
-unsafe fn set_raw_bit<Block: BlockType>(bits: *mut Block, address: Address, value: bool) {
-    let ptr       = bits.offset(address.block_index as isize);
-    let old_block = ptr::read(ptr);
-    let new_block = old_block.with_bit(address.bit_offset, value);
-    ptr::write(ptr, new_block);
+unsafe fn set_raw_bit<Block: BlockType>(bits: *mut Block, span: &SliceSpan,
+                                        address: Address, value: bool) {
+    let block     = if value { Block::one() } else { Block::zero() };
+    store_bits(bits, span, address.block_index, address.bit_offset, 1, block);
+}
+
+// Replaces the bits in `range` of `span` by applying `fun` to them a block
+// at a time. Blocks of the underlying array that the range covers entirely
+// are updated in place.
+//
+// Precondition: `range` is within `span`.
+unsafe fn update_raw_range<Block, F>(bits: *mut Block, span: &SliceSpan,
+                                     range: Range<u64>, fun: F)
+    where Block: BlockType,
+          F: Fn(Block) -> Block {
+
+    let offset = u64::from(span.offset);
+    let limit  = range.end + offset;
+
+    let mut position = range.start + offset;
+    while position < limit {
+        let address = Address::new::<Block>(position);
+        let start   = address.bit_offset;
+        let count   = cmp::min(Block::nbits() - start, (limit - position) as usize);
+
+        if count == Block::nbits() {
+            let ptr = bits.offset(address.block_index as isize);
+            ptr::write(ptr, fun(ptr::read(ptr)));
+        } else {
+            let block = load_block(bits, span, address.block_index);
+            store_bits(bits, span, address.block_index, start, count, fun(block) >> start);
+        }
+
+        position += count as u64;
+    }
 }
 
 impl<'a, Block: BlockType> Bits for BitSlice<'a, Block> {
@@ -381,7 +706,7 @@ impl<'a, Block: BlockType> Bits for BitSlice<'a, Block> {
     fn get_bit(&self, position: u64) -> bool {
         let address = self.span.find_bit::<Block>(position)
             .expect("BitSlice::get_bit: out of bounds");
-        unsafe { get_raw_bit(self.bits, address) }
+        unsafe { get_raw_bit(self.bits, &self.span, address) }
     }
 
     fn get_block(&self, position: usize) -> Block {
@@ -391,13 +716,13 @@ impl<'a, Block: BlockType> Bits for BitSlice<'a, Block> {
     fn get_raw_block(&self, position: usize) -> Block {
         let block_addr = self.span.find_block::<Block>(position)
             .expect("BitSlice::get_block: out of bounds");
-        unsafe { block_addr.read(self.bits) }
+        unsafe { block_addr.read(&self.span, self.bits) }
     }
 
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         let block_addr = self.span.find_bits::<Block>(start, count)
             .expect("BitSlice::get_bits: out of bounds");
-        unsafe { block_addr.read(self.bits) }
+        unsafe { block_addr.read(&self.span, self.bits) }
     }
 }
 
@@ -411,19 +736,23 @@ impl<'a, Block: BlockType> Bits for BitSliceMut<'a, Block> {
     fn get_bit(&self, position: u64) -> bool {
         let address = self.span.find_bit::<Block>(position)
             .expect("BitSliceMut::get_bit: out of bounds");
-        unsafe { get_raw_bit(self.bits, address) }
+        unsafe { get_raw_bit(self.bits, &self.span, address) }
     }
 
     fn get_block(&self, position: usize) -> Block {
+        get_masked_block(self, position)
+    }
+
+    fn get_raw_block(&self, position: usize) -> Block {
         let block_addr = self.span.find_block::<Block>(position)
             .expect("BitSliceMut::get_block: out of bounds");
-        unsafe { block_addr.read(self.bits) }
+        unsafe { block_addr.read(&self.span, self.bits) }
     }
 
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         let block_addr = self.span.find_bits::<Block>(start, count)
             .expect("BitSliceMut::get_bits: out of bounds");
-        unsafe { block_addr.read(self.bits) }
+        unsafe { block_addr.read(&self.span, self.bits) }
     }
 }
 
@@ -432,20 +761,35 @@ impl<'a, Block: BlockType> BitsMut for BitSliceMut<'a, Block> {
         let address = self.span.find_bit::<Block>(position)
             .expect("BitSliceMut::set_bit: out of bounds");
         unsafe {
-            set_raw_bit(self.bits, address, value);
+            set_raw_bit(self.bits, &self.span, address, value);
         }
     }
 
     fn set_block(&mut self, position: usize, value: Block) {
         let block_addr = self.span.find_block::<Block>(position)
             .expect("BitSliceMut::set_block: out of bounds");
-        unsafe { block_addr.write(self.bits, value); }
+        unsafe { block_addr.write(&self.span, self.bits, value); }
     }
 
     fn set_bits(&mut self, start: u64, count: usize, value: Self::Block) {
         let block_addr = self.span.find_bits::<Block>(start, count)
             .expect("BitSliceMut::set_bits: out of bounds");
-        unsafe { block_addr.write(self.bits, value); }
+        unsafe { block_addr.write(&self.span, self.bits, value); }
+    }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        assert!(range.start <= range.end && range.end <= self.len(),
+                "BitSliceMut::set_range: out of bounds");
+
+        let block = if value { !Block::zero() } else { Block::zero() };
+        unsafe { update_raw_range(self.bits, &self.span, range, |_| block); }
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        assert!(range.start <= range.end && range.end <= self.len(),
+                "BitSliceMut::toggle_range: out of bounds");
+
+        unsafe { update_raw_range(self.bits, &self.span, range, |block| !block); }
     }
 }
 
@@ -454,6 +798,14 @@ impl_index_from_bits! {
     impl['a, Block: BlockType] Index<u64> for BitSliceMut<'a, Block>;
 }
 
+impl_bit_ops_from_bits! {
+    impl['a, Block: BlockType] BitOps for BitSlice<'a, Block>;
+}
+
+impl_bit_assign_ops_from_bits_mut! {
+    impl['a, Block: BlockType] BitAssignOps for BitSliceMut<'a, Block>;
+}
+
 impl<'a, Block: BlockType> BitSliceable<Range<u64>> for BitSlice<'a, Block> {
     type Slice = Self;
 
@@ -684,11 +1036,197 @@ impl<'a, Block: BlockType> fmt::Debug for BitSliceMut<'a, Block> {
     }
 }
 
+impl<'a, Block: BlockType> IntoIterator for BitSlice<'a, Block> {
+    type Item = bool;
+    type IntoIter = BitIter<BitSlice<'a, Block>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitIter::new(self)
+    }
+}
+
+impl<'a, 'b, Block: BlockType> IntoIterator for &'b BitSlice<'a, Block> {
+    type Item = bool;
+    type IntoIter = BitIter<BitSlice<'a, Block>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitIter::new(*self)
+    }
+}
+
+impl<'a, 'b, Block: BlockType> IntoIterator for &'b BitSliceMut<'a, Block> {
+    type Item = bool;
+    type IntoIter = BitIter<&'b BitSliceMut<'a, Block>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitIter::new(self)
+    }
+}
+
+/// An iterator over consecutive chunks of a [`BitSlice`](../struct.BitSlice.html).
+///
+/// This is the result of
+/// [`BitSlice::chunks`](../struct.BitSlice.html#method.chunks).
+#[derive(Clone, Debug)]
+pub struct Chunks<'a, Block: BlockType + 'a> {
+    rest:      BitSlice<'a, Block>,
+    chunk_len: u64,
+}
+
+impl<'a, Block: BlockType> Iterator for Chunks<'a, Block> {
+    type Item = BitSlice<'a, Block>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let mid = cmp::min(self.chunk_len, self.rest.len());
+        let (chunk, rest) = self.rest.split_at(mid);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+/// An iterator over consecutive mutable chunks of a
+/// [`BitSliceMut`](../struct.BitSliceMut.html).
+///
+/// This is the result of
+/// [`BitSliceMut::chunks_mut`](../struct.BitSliceMut.html#method.chunks_mut).
+#[derive(Debug)]
+pub struct ChunksMut<'a, Block: BlockType + 'a> {
+    rest:      Option<BitSliceMut<'a, Block>>,
+    chunk_len: u64,
+}
+
+impl<'a, Block: BlockType> Iterator for ChunksMut<'a, Block> {
+    type Item = BitSliceMut<'a, Block>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mid = cmp::min(self.chunk_len, rest.len());
+        let (chunk, rest) = rest.split_at_mut(mid);
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use BitVec;
     use super::*;
 
+    #[cfg(atomic_u8)]
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<BitSlice<u32>>();
+        assert_send_sync::<BitSliceMut<u32>>();
+    }
+
+    #[cfg(all(atomic_u8, thread_scope))]
+    #[test]
+    fn shared_blocks_across_threads() {
+        let mut array = [0u64; 2];
+
+        ::std::thread::scope(|scope| {
+            for mut chunk in BitSliceMut::from_slice(&mut array).chunks_mut(6) {
+                scope.spawn(move || {
+                    let last = chunk.len() - 1;
+                    for i in 0 .. 1000 {
+                        chunk.set_bit(0, i % 2 == 0);
+                        chunk.set_bit(last, i % 3 == 0);
+                    }
+                });
+            }
+        });
+
+        // Each chunk ends with bit 0 clear (999 is odd) and its last bit
+        // set (999 is a multiple of 3).
+        let slice = BitSlice::from_slice(&array);
+        for (i, bit) in slice.into_iter().enumerate() {
+            let expected = i % 6 == 5 || i == 127;
+            assert_eq!( bit, expected, "bit {}", i );
+        }
+    }
+
+    #[test]
+    fn split_at_mut_shared_block() {
+        let mut bytes = [0u8; 2];
+        {
+            let slice = BitSliceMut::from_slice(&mut bytes).bit_slice(2..14);
+            let (mut left, mut right) = slice.split_at_mut(4);
+            assert_eq!( left.len(), 4 );
+            assert_eq!( right.len(), 8 );
+
+            right.set_block(0, 0xFF);
+            left.set_block(0, 0b0101);
+            right.set_bit(7, false);
+            left.set_bits(1, 2, 0b11);
+        }
+
+        assert_eq!( bytes, [0b1101_1100, 0b0001_1111] );
+    }
+
+    #[test]
+    fn aligned_partial_block_write_is_masked() {
+        let mut bytes = [0u8];
+        {
+            let (mut left, right) = BitSliceMut::from_slice(&mut bytes).split_at_mut(3);
+            left.set_block(0, 0xFF);
+            assert_eq!( right.get_block(0), 0 );
+        }
+        assert_eq!( bytes, [0b0000_0111] );
+    }
+
+    #[test]
+    fn split_at() {
+        let bv: BitVec<u8> = bit_vec![true, false, true, true, false, false, true, false,
+                                      true, true];
+        let (left, right) = bv.as_slice().split_at(0);
+        assert!( left.is_empty() );
+        assert_eq!( right, bv );
+
+        let (left, right) = bv.as_slice().bit_slice(1..).split_at(9);
+        assert_eq!( left, bv.bit_slice(1..) );
+        assert!( right.is_empty() );
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let bv: BitVec = bit_vec![true];
+        bv.as_slice().split_at(2);
+    }
+
+    #[test]
+    fn chunks_mut() {
+        let mut bv: BitVec<u8> = BitVec::new_fill(false, 20);
+        for (i, mut chunk) in bv.as_mut_slice().bit_slice(1..).chunks_mut(3).enumerate() {
+            let len = chunk.len();
+            assert_eq!( len, if i < 6 {3} else {1} );
+            chunk.set_bits(0, len as usize, 0b101);
+        }
+
+        let chunks: Vec<BitVec<u8>> = bv.as_slice().bit_slice(1..)
+            .chunks(3).map(|c| c.to_bit_vec()).collect();
+        assert_eq!( chunks.len(), 7 );
+        assert!( chunks[..6].iter().all(|c| *c == bit_vec![true, false, true]) );
+        assert_eq!( chunks[6], bit_vec![true] );
+        assert!( !bv[0] );
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_zero() {
+        let bv: BitVec = bit_vec![true];
+        bv.as_slice().chunks(0);
+    }
+
     #[test]
     fn bit_slice_from_slice() {
         let mut bytes = [0b00001111u8];
@@ -759,5 +1297,30 @@ mod test {
         let slice = base.bit_slice(::std::ops::RangeToInclusive { end: 4 });
         assert_eq!( slice.len(), 5 );
     }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn reinterpret() {
+        use BitsExt;
+
+        let blocks = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210];
+        let slice  = BitSlice::from_slice(&blocks).bit_slice(4 .. 92);
+
+        let bytes = slice.reinterpret::<u8>().unwrap();
+        assert_eq!( bytes, slice.with_block::<u8>() );
+        assert_eq!( bytes.bit_slice(12 ..).reinterpret::<u16>().unwrap(),
+                    slice.bit_slice(12 ..).with_block::<u16>() );
+
+        let words = bytes.reinterpret::<u32>().unwrap();
+        assert_eq!( words, slice.with_block::<u32>() );
+
+        // Widening must not read past the bytes that the slice covers, and
+        // must start at an aligned address.
+        assert!( bytes.reinterpret::<u64>().is_none() );
+        assert!( bytes.bit_slice(4 ..).reinterpret::<u16>().is_none() );
+        assert!( words.reinterpret::<u64>().is_none() );
+        assert!( BitSlice::from_slice(&blocks).reinterpret::<u8>().unwrap()
+                     .reinterpret::<u64>().is_some() );
+    }
 }
 
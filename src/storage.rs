@@ -227,18 +227,79 @@ pub trait BlockType: Copy +
         )
     }
 
+    /// Gathers the bits of `self` at the positions set in `mask` into the
+    /// low bits of the result, in order, like the x86 `PEXT` instruction.
+    ///
+    /// The result has `mask.count_ones()` significant bits; the rest are
+    /// zero.
+    #[inline]
+    fn pext(self, mut mask: Self) -> Self {
+        let mut result = Self::zero();
+        let mut target = 0;
+
+        while mask != Self::zero() {
+            let source = mask.trailing_zeros();
+            if self.get_bit(source) {
+                result = result | Self::nth_mask(target);
+            }
+            target += 1;
+            mask = mask & mask.wrapping_sub(Self::one());
+        }
+
+        result
+    }
+
+    /// Scatters the low bits of `self`, in order, to the positions set in
+    /// `mask`, like the x86 `PDEP` instruction. This inverts `pext`.
+    ///
+    /// The bits of the result not set in `mask` are zero.
+    #[inline]
+    fn pdep(self, mut mask: Self) -> Self {
+        let mut result = Self::zero();
+        let mut source = 0;
+
+        while mask != Self::zero() {
+            let target = mask.trailing_zeros();
+            if self.get_bit(source) {
+                result = result | Self::nth_mask(target);
+            }
+            source += 1;
+            mask = mask & mask.wrapping_sub(Self::one());
+        }
+
+        result
+    }
+
     /// A shift-left operation that does not overflow.
     fn wrapping_shl(self, shift: u32) -> Self;
 
+    /// An addition operation that does not overflow.
+    fn wrapping_add(self, other: Self) -> Self;
+
     /// A subtraction operation that does not overflow.
     fn wrapping_sub(self, other: Self) -> Self;
 
     /// Returns the number of leading zero bits in the given number.
     fn leading_zeros(self) -> usize;
 
+    /// Returns the number of trailing zero bits in the given number.
+    fn trailing_zeros(self) -> usize;
+
+    /// Returns the number of one bits in the given number.
+    fn count_ones(self) -> usize;
+
+    /// Reverses the order of the bits in the given number.
+    fn reverse_bits(self) -> Self;
+
     /// Converts the number to a `usize`, if it fits.
     fn to_usize(self) -> Option<usize>;
 
+    /// Converts the number to a `u64`, keeping only the low 64 bits.
+    fn to_u64_wrapping(self) -> u64;
+
+    /// Converts a `u64` to a block, keeping only as many low bits as fit.
+    fn from_u64_wrapping(value: u64) -> Self;
+
     /// Returns 0.
     fn zero() -> Self;
 
@@ -291,6 +352,11 @@ macro_rules! impl_block_type {
                 self.wrapping_shl(shift)
             }
 
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self {
+                self.wrapping_add(other)
+            }
+
             #[inline]
             fn wrapping_sub(self, other: Self) -> Self {
                 self.wrapping_sub(other)
@@ -301,6 +367,32 @@ macro_rules! impl_block_type {
                 self.leading_zeros() as usize
             }
 
+            #[inline]
+            fn trailing_zeros(self) -> usize {
+                self.trailing_zeros() as usize
+            }
+
+            #[inline]
+            fn count_ones(self) -> usize {
+                self.count_ones() as usize
+            }
+
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                // Reverse the bits within each byte by swapping ever larger
+                // groups, then reverse the bytes. The masks are 0x55…, 0x33…
+                // and 0x0F….
+                let m1: Self = !0 / 3;
+                let m2: Self = !0 / 5;
+                let m4: Self = !0 / 17;
+
+                let x = self;
+                let x = ((x >> 1) & m1) | ((x & m1) << 1);
+                let x = ((x >> 2) & m2) | ((x & m2) << 2);
+                let x = ((x >> 4) & m4) | ((x & m4) << 4);
+                x.swap_bytes()
+            }
+
             #[inline]
             fn to_usize(self) -> Option<usize> {
                 if self as usize as Self == self {
@@ -310,6 +402,16 @@ macro_rules! impl_block_type {
                 }
             }
 
+            #[inline]
+            fn to_u64_wrapping(self) -> u64 {
+                self as u64
+            }
+
+            #[inline]
+            fn from_u64_wrapping(value: u64) -> Self {
+                value as Self
+            }
+
             #[inline]
             fn zero() -> Self {
                 0
@@ -484,6 +586,65 @@ mod test {
         assert_eq!(0b10101010, 0b10101010u8.with_bit(0, false));
     }
 
+    #[test]
+    fn trailing_zeros() {
+        assert_eq!(0, BlockType::trailing_zeros(1u8));
+        assert_eq!(3, BlockType::trailing_zeros(0b1000u16));
+        assert_eq!(31, BlockType::trailing_zeros(1u32 << 31));
+        assert_eq!(64, BlockType::trailing_zeros(0u64));
+    }
+
+    #[test]
+    fn reverse_bits() {
+        assert_eq!(0b1000_0000, BlockType::reverse_bits(1u8));
+        assert_eq!(0b0011_0101, BlockType::reverse_bits(0b1010_1100u8));
+        assert_eq!(0x8000, BlockType::reverse_bits(1u16));
+        assert_eq!(0xF000_0000_0000_0001, BlockType::reverse_bits(0x8000_0000_0000_000Fu64));
+
+        fn prop(n: u32) -> bool {
+            (0 .. 32).all(|i| n.get_bit(i) == BlockType::reverse_bits(n).get_bit(31 - i))
+        }
+        quickcheck(prop as fn(u32) -> bool);
+    }
+
+    #[test]
+    fn pext_and_pdep() {
+        assert_eq!(0b1011, 0b1001_0010u8.pext(0b1011_0010));
+        assert_eq!(0b0010_0010, 0b101u8.pdep(0b1011_0010));
+        assert_eq!(0xFFFF, 0xFFFF_0000u32.pext(0xFFFF_0000));
+        assert_eq!(0, 0xFFu16.pext(0));
+        assert_eq!(0x0123_4567_89AB_CDEF, 0x0123_4567_89AB_CDEFu64.pext(!0));
+        assert_eq!(0x0123_4567_89AB_CDEF, 0x0123_4567_89AB_CDEFu64.pdep(!0));
+        assert_eq!(0b10, 0x8000_0000_0000_0000u64.pext(0x8000_0000_0000_0001));
+        assert_eq!(0b11, 0x8000_0000_0000_0001u64.pext(0x8000_0000_0000_0001));
+        assert_eq!(0x8000_0000_0000_0000, 0b10u64.pdep(0x8000_0000_0000_0001));
+        assert_eq!(0x8000_0000_0000_0001, 0b111u64.pdep(0x8000_0000_0000_0001));
+
+        // The generated integers are small, so we build full-width values
+        // out of generated bits instead.
+        fn from_bools(bits: &[bool]) -> u64 {
+            bits.iter().take(64).enumerate()
+                .fold(0, |acc, (i, &bit)| acc | (bit as u64) << i)
+        }
+
+        fn prop(n: Vec<bool>, mask: Vec<bool>) -> bool {
+            let n      = from_bools(&n);
+            let mask   = from_bools(&mask);
+            let packed = n.pext(mask);
+            packed.checked_shr(mask.count_ones()).unwrap_or(0) == 0
+                && packed.pdep(mask) == n & mask
+                && (n & mask).pext(mask) == packed
+        }
+        quickcheck(prop as fn(Vec<bool>, Vec<bool>) -> bool);
+    }
+
+    #[test]
+    fn count_ones() {
+        assert_eq!(0, BlockType::count_ones(0u8));
+        assert_eq!(3, BlockType::count_ones(0b1011u16));
+        assert_eq!(64, BlockType::count_ones(!0u64));
+    }
+
     #[test]
     fn floor_lg() {
         assert_eq!(0, 1u32.floor_lg());
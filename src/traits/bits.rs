@@ -1,6 +1,10 @@
 use super::BitsMut;
 use storage::{BlockType, Address};
 use BitVec;
+use adapter::BitNot;
+use iter::Positions;
+
+use std::ops::Range;
 
 /// Read-only bit vector operations.
 ///
@@ -121,6 +125,151 @@ pub trait Bits {
         (high_bits << margin) | low_bits
     }
 
+    /// Counts the set bits.
+    ///
+    /// This works a block at a time, so it is cheap even through lazy
+    /// adapters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv1: BitVec = bit_vec![true, true, false, true];
+    /// let bv2: BitVec = bit_vec![false, true, true, true];
+    ///
+    /// assert_eq!( bv1.count_ones(), 3 );
+    /// assert_eq!( bv1.bit_and(&bv2).count_ones(), 2 );
+    /// ```
+    fn count_ones(&self) -> u64 {
+        self.count_ones_in(0 .. self.bit_len())
+    }
+
+    /// Counts the unset bits.
+    fn count_zeros(&self) -> u64 {
+        self.bit_len() - self.count_ones()
+    }
+
+    /// Counts the set bits whose positions are in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start exceeds its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec<u8> = bit_vec![true, true, false, true, false,
+    ///                               false, true, true, false, true];
+    ///
+    /// assert_eq!( bv.count_ones_in(1 .. 9), 4 );
+    /// assert_eq!( bv.count_ones_in(3 .. 3), 0 );
+    /// ```
+    fn count_ones_in(&self, range: Range<u64>) -> u64 {
+        assert!(range.start <= range.end && range.end <= self.bit_len(),
+                "Bits::count_ones_in: out of bounds");
+
+        if range.start == range.end { return 0; }
+
+        let first = Self::Block::div_nbits(range.start);
+        let last  = Self::Block::div_nbits(range.end - 1);
+
+        let first_mask = !Self::Block::low_mask(Self::Block::mod_nbits(range.start));
+        let last_mask  = Self::Block::low_mask(Self::Block::block_bits(range.end, last));
+
+        if first == last {
+            let block = self.get_raw_block(first) & first_mask & last_mask;
+            return block.count_ones() as u64;
+        }
+
+        let mut result = (self.get_raw_block(first) & first_mask).count_ones() as u64;
+        for i in first + 1 .. last {
+            result += self.get_raw_block(i).count_ones() as u64;
+        }
+        result + (self.get_raw_block(last) & last_mask).count_ones() as u64
+    }
+
+    /// Finds the position of the first set bit, if any.
+    ///
+    /// This searches a block at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![false, false, true, false, true, false];
+    ///
+    /// assert_eq!( bv.first_one(), Some(2) );
+    /// assert_eq!( bv.last_one(), Some(4) );
+    /// assert_eq!( bv.next_one(3), Some(4) );
+    /// assert_eq!( bv.prev_one(4), Some(2) );
+    /// assert_eq!( bv.first_zero(), Some(0) );
+    /// assert_eq!( bv.next_zero(2), Some(3) );
+    /// ```
+    fn first_one(&self) -> Option<u64> {
+        self.next_one(0)
+    }
+
+    /// Finds the position of the last set bit, if any.
+    fn last_one(&self) -> Option<u64> {
+        self.prev_one(self.bit_len())
+    }
+
+    /// Finds the position of the first set bit at or after `from`, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from > self.bit_len()`.
+    fn next_one(&self, from: u64) -> Option<u64> {
+        let len = self.bit_len();
+        assert!(from <= len, "Bits::next_one: out of bounds");
+        Positions::new(self, from, len).next()
+    }
+
+    /// Finds the position of the last set bit before `before`, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `before > self.bit_len()`.
+    fn prev_one(&self, before: u64) -> Option<u64> {
+        assert!(before <= self.bit_len(), "Bits::prev_one: out of bounds");
+        Positions::new(self, 0, before).next_back()
+    }
+
+    /// Finds the position of the first unset bit, if any.
+    fn first_zero(&self) -> Option<u64> {
+        self.next_zero(0)
+    }
+
+    /// Finds the position of the last unset bit, if any.
+    fn last_zero(&self) -> Option<u64> {
+        self.prev_zero(self.bit_len())
+    }
+
+    /// Finds the position of the first unset bit at or after `from`, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from > self.bit_len()`.
+    fn next_zero(&self, from: u64) -> Option<u64> {
+        let len = self.bit_len();
+        assert!(from <= len, "Bits::next_zero: out of bounds");
+        Positions::new(BitNot::new(self), from, len).next()
+    }
+
+    /// Finds the position of the last unset bit before `before`, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `before > self.bit_len()`.
+    fn prev_zero(&self, before: u64) -> Option<u64> {
+        assert!(before <= self.bit_len(), "Bits::prev_zero: out of bounds");
+        Positions::new(BitNot::new(self), 0, before).next_back()
+    }
+
     /// Copies the bits into a new allocated [`BitVec`].
     ///
     /// [`BitVec`]: ../struct.BitVec.html
@@ -162,6 +311,14 @@ impl<'a, T: Bits + ?Sized> Bits for &'a T {
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         T::get_bits(*self, start, count)
     }
+
+    fn count_ones(&self) -> u64 {
+        T::count_ones(*self)
+    }
+
+    fn count_ones_in(&self, range: Range<u64>) -> u64 {
+        T::count_ones_in(*self, range)
+    }
 }
 
 impl<'a, T: Bits + ?Sized> Bits for &'a mut T {
@@ -190,6 +347,14 @@ impl<'a, T: Bits + ?Sized> Bits for &'a mut T {
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         T::get_bits(*self, start, count)
     }
+
+    fn count_ones(&self) -> u64 {
+        T::count_ones(*self)
+    }
+
+    fn count_ones_in(&self, range: Range<u64>) -> u64 {
+        T::count_ones_in(*self, range)
+    }
 }
 
 impl<Block: BlockType> Bits for Box<Bits<Block = Block>> {
@@ -218,6 +383,14 @@ impl<Block: BlockType> Bits for Box<Bits<Block = Block>> {
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         (**self).get_bits(start, count)
     }
+
+    fn count_ones(&self) -> u64 {
+        (**self).count_ones()
+    }
+
+    fn count_ones_in(&self, range: Range<u64>) -> u64 {
+        (**self).count_ones_in(range)
+    }
 }
 
 impl<Block: BlockType> Bits for Box<BitsMut<Block = Block>> {
@@ -246,6 +419,14 @@ impl<Block: BlockType> Bits for Box<BitsMut<Block = Block>> {
     fn get_bits(&self, start: u64, count: usize) -> Self::Block {
         (**self).get_bits(start, count)
     }
+
+    fn count_ones(&self) -> u64 {
+        (**self).count_ones()
+    }
+
+    fn count_ones_in(&self, range: Range<u64>) -> u64 {
+        (**self).count_ones_in(range)
+    }
 }
 
 impl<Block: BlockType> Bits for [Block] {
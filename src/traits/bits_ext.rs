@@ -1,5 +1,10 @@
-use super::Bits;
+use super::{Bits, BitsPush};
 use adapter::*;
+use {BitVec, LengthMismatch, LengthPolicy};
+use BlockType;
+use iter::{BitIter, Ones, Zeros};
+
+use std::cmp::{self, Ordering};
 
 /// Extension trait for adapter operations on bit slices.
 ///
@@ -7,6 +12,10 @@ use adapter::*;
 /// and perform operations as needed. To eagerly evaluate a result, copy
 /// it into a vector using the [`Bits::to_bit_vec`] method, as in the example below.
 ///
+/// The operators `&`, `|`, `^` and `!` on `&BitVec`, `BitSlice` and the adapters
+/// build the same adapters as `bit_and`, `bit_or`, `bit_xor` and `bit_not`, so
+/// `&bv1 & &bv2` is another way to write `bv1.bit_and(&bv2)`.
+///
 /// This trait is currently `pub use`d from the [`adapter`] module, but that alias
 /// is deprecated.
 ///
@@ -160,6 +169,147 @@ pub trait BitsExt: Bits {
         BitXor::new(self, other)
     }
 
+    /// Returns an object that lazily computes the bit-wise conjunction
+    /// of `self` with the complement of `other`, which is the set
+    /// difference of the two.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Unlike `self.bit_and(other.bit_not())`, this combines the blocks
+    /// of the operands in a single step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv1: BitVec = bit_vec![true, true, false, false];
+    /// let bv2: BitVec = bit_vec![true, false, true, false];
+    ///
+    /// assert_eq!( bv1.bit_and_not(&bv2), bit_vec![false, true, false, false] );
+    /// ```
+    fn bit_and_not<Other>(&self, other: Other) -> BitAndNot<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitAndNot::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise conjunction
+    /// of `self` with the complement of `other`, which is the set
+    /// difference of the two.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Consumes `self`.
+    fn into_bit_and_not<Other>(self, other: Other) -> BitAndNot<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitAndNot::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *nand* of
+    /// two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    fn bit_nand<Other>(&self, other: Other) -> BitNand<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitNand::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *nand* of
+    /// two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Consumes `self`.
+    fn into_bit_nand<Other>(self, other: Other) -> BitNand<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitNand::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *nor* of
+    /// two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    fn bit_nor<Other>(&self, other: Other) -> BitNor<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitNor::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *nor* of
+    /// two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Consumes `self`.
+    fn into_bit_nor<Other>(self, other: Other) -> BitNor<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitNor::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *xnor*, or
+    /// equivalence, of two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    fn bit_xnor<Other>(&self, other: Other) -> BitXnor<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitXnor::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise *xnor*, or
+    /// equivalence, of two bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Consumes `self`.
+    fn into_bit_xnor<Other>(self, other: Other) -> BitXnor<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitXnor::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise implication
+    /// from `self` to `other`, that is, `!self | other`.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    fn bit_implies<Other>(&self, other: Other) -> BitImplies<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitImplies::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the bit-wise implication
+    /// from `self` to `other`, that is, `!self | other`.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the two.
+    ///
+    /// Consumes `self`.
+    fn into_bit_implies<Other>(self, other: Other) -> BitImplies<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitImplies::new(self, other)
+    }
+
     /// Returns an object that lazily zips a function over the blocks of
     /// two bit-vector-like.
     ///
@@ -194,7 +344,769 @@ pub trait BitsExt: Bits {
 
         BitZip::new(self, other, fun)
     }
+
+    /// Like [`bit_and`](#method.bit_and), with `policy` deciding what
+    /// happens when the lengths of the operands differ.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv1: BitVec = bit_vec![true, false, true, true];
+    /// let bv2: BitVec = bit_vec![true, true];
+    ///
+    /// let and = bv1.bit_and_with(&bv2, LengthPolicy::ExtendWith(true)).unwrap();
+    /// assert_eq!( and, bit_vec![true, false, true, true] );
+    ///
+    /// let and = bv1.bit_and_with(&bv2, LengthPolicy::Truncate).unwrap();
+    /// assert_eq!( and, bit_vec![true, false] );
+    ///
+    /// let err = bv1.bit_and_with(&bv2, LengthPolicy::Strict).unwrap_err();
+    /// assert_eq!( err, LengthMismatch { left: 4, right: 2 } );
+    /// ```
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_and_with<Other>(&self, other: Other, policy: LengthPolicy)
+        -> Result<BitAnd<Padded<&Self>, Padded<Other>>, LengthMismatch>
+        where Other: Bits<Block = Self::Block> {
+
+        self.into_bit_and_with(other, policy)
+    }
+
+    /// Like [`into_bit_and`](#method.into_bit_and), with `policy` deciding
+    /// what happens when the lengths of the operands differ.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn into_bit_and_with<Other>(self, other: Other, policy: LengthPolicy)
+        -> Result<BitAnd<Padded<Self>, Padded<Other>>, LengthMismatch>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        let (op1, op2) = pad_operands(self, other, policy)?;
+        Ok(BitAnd::new(op1, op2))
+    }
+
+    /// Like [`bit_or`](#method.bit_or), with `policy` deciding what
+    /// happens when the lengths of the operands differ.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_or_with<Other>(&self, other: Other, policy: LengthPolicy)
+        -> Result<BitOr<Padded<&Self>, Padded<Other>>, LengthMismatch>
+        where Other: Bits<Block = Self::Block> {
+
+        self.into_bit_or_with(other, policy)
+    }
+
+    /// Like [`into_bit_or`](#method.into_bit_or), with `policy` deciding
+    /// what happens when the lengths of the operands differ.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn into_bit_or_with<Other>(self, other: Other, policy: LengthPolicy)
+        -> Result<BitOr<Padded<Self>, Padded<Other>>, LengthMismatch>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        let (op1, op2) = pad_operands(self, other, policy)?;
+        Ok(BitOr::new(op1, op2))
+    }
+
+    /// Like [`bit_xor`](#method.bit_xor), with `policy` deciding what
+    /// happens when the lengths of the operands differ.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_xor_with<Other>(&self, other: Other, policy: LengthPolicy)
+        -> Result<BitXor<Padded<&Self>, Padded<Other>>, LengthMismatch>
+        where Other: Bits<Block = Self::Block> {
+
+        self.into_bit_xor_with(other, policy)
+    }
+
+    /// Like [`into_bit_xor`](#method.into_bit_xor), with `policy` deciding
+    /// what happens when the lengths of the operands differ.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn into_bit_xor_with<Other>(self, other: Other, policy: LengthPolicy)
+        -> Result<BitXor<Padded<Self>, Padded<Other>>, LengthMismatch>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        let (op1, op2) = pad_operands(self, other, policy)?;
+        Ok(BitXor::new(op1, op2))
+    }
+
+    /// Returns an object that lazily zips a function over the blocks of
+    /// three bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the three.
+    fn bit_zip3<Other1, Other2, F>(&self, other1: Other1, other2: Other2, fun: F)
+        -> BitZip3<&Self, Other1, Other2, F>
+        where Other1: Bits<Block = Self::Block>,
+              Other2: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block, Self::Block) -> Self::Block {
+
+        BitZip3::new(self, other1, other2, fun)
+    }
+
+    /// Returns an object that lazily zips a function over the blocks of
+    /// three bit-vector-likes.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the three.
+    ///
+    /// Consumes `self`.
+    fn into_bit_zip3<Other1, Other2, F>(self, other1: Other1, other2: Other2, fun: F)
+        -> BitZip3<Self, Other1, Other2, F>
+        where Self: Sized,
+              Other1: Bits<Block = Self::Block>,
+              Other2: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block, Self::Block) -> Self::Block {
+
+        BitZip3::new(self, other1, other2, fun)
+    }
+
+    /// Returns an object that lazily selects, using `self` as the mask,
+    /// the bits of `then` where `self` is set and the bits of `otherwise`
+    /// where it is clear.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the three.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mask: BitVec = bit_vec![true,  false, true,  false];
+    /// let a:    BitVec = bit_vec![true,  true,  false, false];
+    /// let b:    BitVec = bit_vec![false, false, true,  true];
+    ///
+    /// assert_eq!( mask.bit_select(&a, &b), bit_vec![true, false, false, true] );
+    /// ```
+    fn bit_select<Then, Otherwise>(&self, then: Then, otherwise: Otherwise)
+        -> BitSelect<&Self, Then, Otherwise>
+        where Then: Bits<Block = Self::Block>,
+              Otherwise: Bits<Block = Self::Block> {
+
+        self.into_bit_select(then, otherwise)
+    }
+
+    /// Returns an object that lazily selects, using `self` as the mask,
+    /// the bits of `then` where `self` is set and the bits of `otherwise`
+    /// where it is clear.
+    ///
+    /// If the lengths of the operands differ, the result will have
+    /// the minimum of the three.
+    ///
+    /// Consumes `self`.
+    fn into_bit_select<Then, Otherwise>(self, then: Then, otherwise: Otherwise)
+        -> BitSelect<Self, Then, Otherwise>
+        where Self: Sized,
+              Then: Bits<Block = Self::Block>,
+              Otherwise: Bits<Block = Self::Block> {
+
+        BitZip3::new(self, then, otherwise, select_block)
+    }
+
+    /// Like [`bit_zip`](#method.bit_zip), with `policy` deciding what
+    /// happens when the lengths of the operands differ. The zipping
+    /// function `fun` receives just the two blocks.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_zip_with<Other, F>(&self, other: Other, fun: F, policy: LengthPolicy)
+        -> Result<BitZip<Padded<&Self>, Padded<Other>, F>, LengthMismatch>
+        where Other: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block) -> Self::Block {
+
+        self.into_bit_zip_with(other, fun, policy)
+    }
+
+    /// Like [`into_bit_zip`](#method.into_bit_zip), with `policy` deciding
+    /// what happens when the lengths of the operands differ. The zipping
+    /// function `fun` receives just the two blocks.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the operands have different
+    /// lengths.
+    ///
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn into_bit_zip_with<Other, F>(self, other: Other, fun: F, policy: LengthPolicy)
+        -> Result<BitZip<Padded<Self>, Padded<Other>, F>, LengthMismatch>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block) -> Self::Block {
+
+        let (op1, op2) = pad_operands(self, other, policy)?;
+        Ok(BitZip::new(op1, op2, fun))
+    }
+
+    /// Returns an object that lazily computes the sum of two
+    /// bit-vector-likes, treated as unsigned integers with the
+    /// least-significant bit first.
+    ///
+    /// If the lengths of the operands differ, the result will have the
+    /// minimum of the two. The sum wraps around modulo two to that length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// // 11 + 6 = 17, which wraps around to 1.
+    /// let bv1: BitVec = bit_vec![true, true, false, true];
+    /// let bv2: BitVec = bit_vec![false, true, true, false];
+    /// assert_eq!( bv1.bit_add(&bv2), bit_vec![true, false, false, false] );
+    /// ```
+    fn bit_add<Other>(&self, other: Other) -> BitAdd<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitAdd::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the sum of two
+    /// bit-vector-likes, treated as unsigned integers with the
+    /// least-significant bit first.
+    ///
+    /// If the lengths of the operands differ, the result will have the
+    /// minimum of the two. The sum wraps around modulo two to that length.
+    ///
+    /// Consumes `self`.
+    fn into_bit_add<Other>(self, other: Other) -> BitAdd<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitAdd::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the difference of two
+    /// bit-vector-likes, treated as unsigned integers with the
+    /// least-significant bit first.
+    ///
+    /// If the lengths of the operands differ, the result will have the
+    /// minimum of the two. The difference wraps around modulo two to that
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// // 6 - 11 = -5, which wraps around to 11.
+    /// let bv1: BitVec = bit_vec![false, true, true, false];
+    /// let bv2: BitVec = bit_vec![true, true, false, true];
+    /// assert_eq!( bv1.bit_sub(&bv2), bit_vec![true, true, false, true] );
+    /// ```
+    fn bit_sub<Other>(&self, other: Other) -> BitSub<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitSub::new(self, other)
+    }
+
+    /// Returns an object that lazily computes the difference of two
+    /// bit-vector-likes, treated as unsigned integers with the
+    /// least-significant bit first.
+    ///
+    /// If the lengths of the operands differ, the result will have the
+    /// minimum of the two. The difference wraps around modulo two to that
+    /// length.
+    ///
+    /// Consumes `self`.
+    fn into_bit_sub<Other>(self, other: Other) -> BitSub<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitSub::new(self, other)
+    }
+
+    /// Compares two bit-vector-likes as unsigned integers with the
+    /// least-significant bit first.
+    ///
+    /// Unlike `Ord` on bit vectors, which compares blocks starting from the
+    /// lowest index, this compares the most significant bits first. It
+    /// treats missing high bits as zeros, so operands of different lengths
+    /// can be equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut bv1: BitVec<u8> = BitVec::new_fill(false, 9);
+    /// let mut bv2: BitVec<u8> = BitVec::new_fill(false, 9);
+    /// bv1.set(8, true);
+    /// bv2.set(0, true);
+    ///
+    /// assert_eq!( bv1.numeric_cmp(&bv2), Ordering::Greater );
+    /// assert!( bv1 < bv2 );
+    ///
+    /// let bv3: BitVec<u8> = bit_vec![true];
+    /// assert_eq!( bv2.numeric_cmp(&bv3), Ordering::Equal );
+    /// ```
+    fn numeric_cmp<Other>(&self, other: Other) -> Ordering
+        where Other: Bits<Block = Self::Block> {
+
+        let len1 = self.block_len();
+        let len2 = other.block_len();
+
+        for i in (0 .. cmp::max(len1, len2)).rev() {
+            let block1 = if i < len1 { self.get_block(i) } else { Self::Block::zero() };
+            let block2 = if i < len2 { other.get_block(i) } else { Self::Block::zero() };
+
+            match block1.cmp(&block2) {
+                Ordering::Equal => (),
+                ordering        => return ordering,
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    /// Shifts the bits of `self` by `shift` places toward the higher
+    /// indices, filling with zeros.
+    ///
+    /// The result has the same length as `self`; bits shifted past the end
+    /// are discarded. This is the bit-vector analogue of `<<` on integers,
+    /// whose bits are also numbered from the least significant end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![true, true, false, true];
+    /// assert_eq!( bv.bit_shl(1), bit_vec![false, true, true, false] );
+    /// ```
+    fn bit_shl(&self, shift: u64) -> BitShl<&Self> {
+        BitShl::new(self, shift, false)
+    }
+
+    /// Shifts the bits of `self` toward the higher indices, filling with
+    /// zeros.
+    ///
+    /// Consumes `self`.
+    fn into_bit_shl(self, shift: u64) -> BitShl<Self>
+        where Self: Sized {
+
+        BitShl::new(self, shift, false)
+    }
+
+    /// Shifts the bits of `self` toward the higher indices, filling with
+    /// `fill`.
+    fn bit_shl_fill(&self, shift: u64, fill: bool) -> BitShl<&Self> {
+        BitShl::new(self, shift, fill)
+    }
+
+    /// Shifts the bits of `self` toward the higher indices, filling with
+    /// `fill`.
+    ///
+    /// Consumes `self`.
+    fn into_bit_shl_fill(self, shift: u64, fill: bool) -> BitShl<Self>
+        where Self: Sized {
+
+        BitShl::new(self, shift, fill)
+    }
+
+    /// Shifts the bits of `self` by `shift` places toward the lower
+    /// indices, filling with zeros.
+    ///
+    /// The result has the same length as `self`; bits shifted past the start
+    /// are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![true, true, false, true];
+    /// assert_eq!( bv.bit_shr(1), bit_vec![true, false, true, false] );
+    /// ```
+    fn bit_shr(&self, shift: u64) -> BitShr<&Self> {
+        BitShr::new(self, shift, false)
+    }
+
+    /// Shifts the bits of `self` toward the lower indices, filling with
+    /// zeros.
+    ///
+    /// Consumes `self`.
+    fn into_bit_shr(self, shift: u64) -> BitShr<Self>
+        where Self: Sized {
+
+        BitShr::new(self, shift, false)
+    }
+
+    /// Shifts the bits of `self` toward the lower indices, filling with
+    /// `fill`.
+    fn bit_shr_fill(&self, shift: u64, fill: bool) -> BitShr<&Self> {
+        BitShr::new(self, shift, fill)
+    }
+
+    /// Shifts the bits of `self` toward the lower indices, filling with
+    /// `fill`.
+    ///
+    /// Consumes `self`.
+    fn into_bit_shr_fill(self, shift: u64, fill: bool) -> BitShr<Self>
+        where Self: Sized {
+
+        BitShr::new(self, shift, fill)
+    }
+
+    /// Reverses the order of the bits of `self`.
+    ///
+    /// Each block of the result is read from the source with a single
+    /// `get_bits` and reversed with `BlockType::reverse_bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![true, true, false, true, false];
+    /// assert_eq!( bv.bit_reverse(), bit_vec![false, true, false, true, true] );
+    /// ```
+    fn bit_reverse(&self) -> BitReverse<&Self> {
+        BitReverse::new(self)
+    }
+
+    /// Reverses the order of the bits of `self`.
+    ///
+    /// Consumes `self`.
+    fn into_bit_reverse(self) -> BitReverse<Self>
+        where Self: Sized {
+
+        BitReverse::new(self)
+    }
+
+    /// Returns every `k`th bit of `self`, starting with bit `i`.
+    ///
+    /// This recovers component `i` of `k` bit vectors interleaved by
+    /// [`adapter::bit_interleave`].
+    ///
+    /// # Panics
+    ///
+    /// If `i >= k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![true, false, false, true, true, false, true];
+    /// assert_eq!( bv.bit_deinterleave(3, 0), bit_vec![true, true, true] );
+    /// assert_eq!( bv.bit_deinterleave(3, 2), bit_vec![false, false] );
+    /// ```
+    ///
+    /// [`adapter::bit_interleave`]: adapter/fn.bit_interleave.html
+    fn bit_deinterleave(&self, k: usize, i: usize) -> BitDeinterleave<&Self> {
+        BitDeinterleave::new(self, k, i)
+    }
+
+    /// Returns every `k`th bit of `self`, starting with bit `i`.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= k`.
+    fn into_bit_deinterleave(self, k: usize, i: usize) -> BitDeinterleave<Self>
+        where Self: Sized {
+
+        BitDeinterleave::new(self, k, i)
+    }
+
+    /// Presents the bits of `self` in blocks of type `NewBlock`.
+    ///
+    /// This lets bit vectors with different block types meet, for example
+    /// to compare a `BitVec<u8>` read from a file with a `BitVec<u64>`. For
+    /// a [`BitSlice`], [`BitSlice::reinterpret`] may avoid the conversion
+    /// altogether.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv1: BitVec<u8>  = BitVec::from_bits(&[0x34u8, 0x12, 0x01]);
+    /// let bv2: BitVec<u64> = BitVec::from_bits(&[0x1_1234u64]);
+    ///
+    /// assert_eq!( bv1.with_block::<u64>(), bv2.bit_slice(.. 24) );
+    /// assert_eq!( bv2.bit_slice(.. 24).with_block::<u8>(), bv1 );
+    /// assert_eq!( bv1.with_block::<u64>().bit_and(&bv2).count_ones(), 6 );
+    /// ```
+    ///
+    /// [`BitSlice`]: struct.BitSlice.html
+    /// [`BitSlice::reinterpret`]: struct.BitSlice.html#method.reinterpret
+    fn with_block<NewBlock: BlockType>(&self) -> BlockConvert<&Self, NewBlock> {
+        BlockConvert::new(self)
+    }
+
+    /// Presents the bits of `self` in blocks of type `NewBlock`.
+    ///
+    /// Consumes `self`.
+    fn into_with_block<NewBlock: BlockType>(self) -> BlockConvert<Self, NewBlock>
+        where Self: Sized {
+
+        BlockConvert::new(self)
+    }
+
+    /// Gathers the bits of `self` at the positions set in `mask` into a new
+    /// [`BitVec`], in order.
+    ///
+    /// Unlike most methods of this trait, this one is eager. It works a
+    /// block at a time using [`BlockType::pext`].
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != mask.bit_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv:   BitVec = bit_vec![true, false, false, true, true];
+    /// let mask: BitVec = bit_vec![true, true, false, true, false];
+    /// assert_eq!( bv.bit_extract(&mask), bit_vec![true, false, true] );
+    /// ```
+    ///
+    /// [`BitVec`]: struct.BitVec.html
+    /// [`BlockType::pext`]: trait.BlockType.html#method.pext
+    fn bit_extract<Mask>(&self, mask: Mask) -> BitVec<Self::Block>
+        where Mask: Bits<Block = Self::Block> {
+
+        assert_eq!( self.bit_len(), mask.bit_len(),
+                    "BitsExt::bit_extract: arguments have different lengths" );
+
+        let mut result = BitVec::new();
+
+        for i in 0 .. self.block_len() {
+            let mask_block = mask.get_block(i);
+            let packed     = self.get_raw_block(i).pext(mask_block);
+            result.push_bits(packed, mask_block.count_ones());
+        }
+
+        result
+    }
+
+    /// Scatters the bits of `self`, in order, to the positions set in the
+    /// first `len` bits of `mask`, returning a new [`BitVec`] of length `len`
+    /// whose other bits are zero. This inverts `bit_extract`.
+    ///
+    /// Unlike most methods of this trait, this one is eager. It works a
+    /// block at a time using [`BlockType::pdep`]. Bits of `self` beyond
+    /// those needed are ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `mask.bit_len() < len`, or if `self` has fewer bits than the
+    /// first `len` bits of `mask` has ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv:   BitVec = bit_vec![true, false, true];
+    /// let mask: BitVec = bit_vec![true, true, false, true, false];
+    /// assert_eq!( bv.bit_deposit(&mask, 5),
+    ///             bit_vec![true, false, false, true, false] );
+    /// ```
+    ///
+    /// [`BitVec`]: struct.BitVec.html
+    /// [`BlockType::pdep`]: trait.BlockType.html#method.pdep
+    fn bit_deposit<Mask>(&self, mask: Mask, len: u64) -> BitVec<Self::Block>
+        where Mask: Bits<Block = Self::Block> {
+
+        assert!( len <= mask.bit_len(), "BitsExt::bit_deposit: mask too short" );
+
+        let mut result = BitVec::with_capacity(len);
+        let mut used   = 0;
+
+        for i in 0 .. Self::Block::ceil_div_nbits(len) {
+            let count      = Self::Block::block_bits(len, i);
+            let mask_block = mask.get_bits(Self::Block::mul_nbits(i), count);
+            let ones       = mask_block.count_ones();
+
+            assert!( used + ones as u64 <= self.bit_len(),
+                     "BitsExt::bit_deposit: not enough bits" );
+
+            let source = if ones == 0 {
+                Self::Block::zero()
+            } else {
+                self.get_bits(used, ones)
+            };
+            result.push_bits(source.pdep(mask_block), count);
+            used += ones as u64;
+        }
+
+        result
+    }
+
+    /// Returns an iterator over the bits of `self`.
+    ///
+    /// The iterator reads the underlying bits a block at a time, and can be
+    /// consumed from either end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![true, false, true];
+    ///
+    /// let bits: Vec<bool> = bv.iter_bits().collect();
+    /// assert_eq!( bits, vec![true, false, true] );
+    ///
+    /// let mut iter = bv.iter_bits();
+    /// assert_eq!( iter.next_back(), Some(true) );
+    /// assert_eq!( iter.next_back(), Some(false) );
+    /// assert_eq!( iter.len(), 1 );
+    /// ```
+    fn iter_bits(&self) -> BitIter<&Self> {
+        BitIter::new(self)
+    }
+
+    /// Returns an iterator over the bits of `self`.
+    ///
+    /// Consumes `self`.
+    fn into_iter_bits(self) -> BitIter<Self>
+        where Self: Sized {
+
+        BitIter::new(self)
+    }
+
+    /// Returns an iterator over the positions of the set bits of `self`,
+    /// in increasing order.
+    ///
+    /// Blocks with no set bits are skipped entirely. The iterator can also
+    /// be consumed from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![false, true, true, false, true];
+    ///
+    /// assert_eq!( bv.ones().collect::<Vec<_>>(), vec![1, 2, 4] );
+    /// assert_eq!( bv.ones().rev().collect::<Vec<_>>(), vec![4, 2, 1] );
+    /// ```
+    fn ones(&self) -> Ones<&Self> {
+        Ones::new(self, 0)
+    }
+
+    /// Returns an iterator over the positions of the set bits of `self`,
+    /// starting at bit `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `start > self.bit_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![false, true, true, false, true];
+    /// assert_eq!( bv.ones_from(2).collect::<Vec<_>>(), vec![2, 4] );
+    /// ```
+    fn ones_from(&self, start: u64) -> Ones<&Self> {
+        Ones::new(self, start)
+    }
+
+    /// Returns an iterator over the positions of the unset bits of `self`,
+    /// in increasing order.
+    ///
+    /// Blocks with every bit set are skipped entirely. The iterator can also
+    /// be consumed from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let bv: BitVec = bit_vec![false, true, true, false, true];
+    /// assert_eq!( bv.zeros().collect::<Vec<_>>(), vec![0, 3] );
+    /// ```
+    fn zeros(&self) -> Zeros<&Self> {
+        Zeros::new(self, 0)
+    }
+
+    /// Returns an iterator over the positions of the unset bits of `self`,
+    /// starting at bit `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `start > self.bit_len()`.
+    fn zeros_from(&self, start: u64) -> Zeros<&Self> {
+        Zeros::new(self, start)
+    }
 }
 
 impl<T: Bits> BitsExt for T {}
 
+// The block function of `bit_select`.
+fn select_block<Block: BlockType>(mask: Block, then: Block, otherwise: Block) -> Block {
+    (mask & then) | (!mask & otherwise)
+}
+
+// An operand padded as a `LengthPolicy` requires.
+type Padded<T> = BitConcat<T, BitFill<<T as Bits>::Block>>;
+
+// Pads both operands with the bits that `policy` calls for, which leaves
+// them at the same length unless the policy is to truncate.
+fn pad_operands<T, U>(op1: T, op2: U, policy: LengthPolicy)
+                      -> Result<(Padded<T>, Padded<U>), LengthMismatch>
+    where T: Bits,
+          U: Bits<Block = T::Block> {
+
+    let (pad1, pad2, fill) = policy.padding(op1.bit_len(), op2.bit_len())?;
+    Ok((BitConcat::new(op1, BitFill::new(fill, pad1)),
+        BitConcat::new(op2, BitFill::new(fill, pad2))))
+}
+
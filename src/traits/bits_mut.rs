@@ -1,6 +1,9 @@
 use super::Bits;
 use storage::{BlockType, Address};
 
+use std::cmp;
+use std::ops::Range;
+
 /// Mutable bit vector operations that don’t affect the length.
 ///
 /// Minimal complete definition is `set_bit` or `set_block`, since each
@@ -82,6 +85,133 @@ pub trait BitsMut: Bits {
         self.set_block(address.block_index, new_block1);
         self.set_block(address.block_index + 1, new_block2);
     }
+
+    /// Sets every bit in `range` to `value`.
+    ///
+    /// The default implementation writes the partial blocks at either end
+    /// of the range using `set_bits`, and the whole blocks in between
+    /// using `set_block`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![false; 6];
+    /// bv.set_range(1 .. 4, true);
+    /// assert_eq!( bv, bit_vec![false, true, true, true, false, false] );
+    /// ```
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        assert!(range.start <= range.end && range.end <= self.bit_len(),
+                "BitsMut::set_range: out of bounds");
+
+        let block = if value { !Self::Block::zero() } else { Self::Block::zero() };
+        update_range(self, range, |_| block);
+    }
+
+    /// Clears every bit in `range`.
+    ///
+    /// The default implementation calls `set_range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    fn clear_range(&mut self, range: Range<u64>) {
+        self.set_range(range, false);
+    }
+
+    /// Flips every bit in `range`.
+    ///
+    /// The default implementation works a block at a time, like
+    /// `set_range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![false, true, false, true];
+    /// bv.toggle_range(1 .. 3);
+    /// assert_eq!( bv, bit_vec![false, false, true, true] );
+    /// ```
+    fn toggle_range(&mut self, range: Range<u64>) {
+        assert!(range.start <= range.end && range.end <= self.bit_len(),
+                "BitsMut::toggle_range: out of bounds");
+
+        update_range(self, range, |block| !block);
+    }
+}
+
+// Replaces the bits in `range` by applying `fun` to them a block at a time:
+// first the bits up to a block boundary, then whole blocks, then the rest.
+fn update_range<T, F>(bits: &mut T, range: Range<u64>, fun: F)
+    where T: BitsMut + ?Sized,
+          F: Fn(T::Block) -> T::Block {
+
+    let nbits = T::Block::nbits();
+
+    let mut position = range.start;
+    while position < range.end {
+        let offset = T::Block::mod_nbits(position);
+        let count  = cmp::min(nbits - offset, (range.end - position) as usize);
+
+        if count == nbits {
+            let index = T::Block::div_nbits(position);
+            let block = bits.get_raw_block(index);
+            bits.set_block(index, fun(block));
+        } else {
+            let block = bits.get_bits(position, count);
+            bits.set_bits(position, count, fun(block));
+        }
+
+        position += count as u64;
+    }
+}
+
+// Like `update_range`, but for an array slice of blocks, which can be
+// updated in place.
+//
+// Precondition: `range` is in bounds.
+fn update_block_range<Block, F>(blocks: &mut [Block], range: Range<u64>, fun: F)
+    where Block: BlockType,
+          F: Fn(Block) -> Block {
+
+    let first = Address::new::<Block>(range.start);
+    let last  = Address::new::<Block>(range.end);
+
+    if first.block_index == last.block_index {
+        if first.bit_offset < last.bit_offset {
+            let block = &mut blocks[first.block_index];
+            let count = last.bit_offset - first.bit_offset;
+            *block = block.with_bits(first.bit_offset, count, fun(*block) >> first.bit_offset);
+        }
+        return;
+    }
+
+    let mut middle = first.block_index;
+    if first.bit_offset > 0 {
+        let block = &mut blocks[first.block_index];
+        let count = Block::nbits() - first.bit_offset;
+        *block = block.with_bits(first.bit_offset, count, fun(*block) >> first.bit_offset);
+        middle += 1;
+    }
+
+    for block in &mut blocks[middle .. last.block_index] {
+        *block = fun(*block);
+    }
+
+    if last.bit_offset > 0 {
+        let block = &mut blocks[last.block_index];
+        *block = block.with_bits(0, last.bit_offset, fun(*block));
+    }
 }
 
 impl<'a, T: BitsMut + ?Sized> BitsMut for &'a mut T {
@@ -96,6 +226,14 @@ impl<'a, T: BitsMut + ?Sized> BitsMut for &'a mut T {
     fn set_bits(&mut self, start: u64, count: usize, value: Self::Block) {
         T::set_bits(*self, start, count, value);
     }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        T::set_range(*self, range, value);
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        T::toggle_range(*self, range);
+    }
 }
 
 impl<Block: BlockType> BitsMut for Box<BitsMut<Block = Block>> {
@@ -110,6 +248,14 @@ impl<Block: BlockType> BitsMut for Box<BitsMut<Block = Block>> {
     fn set_bits(&mut self, start: u64, len: usize, value: Block) {
         (**self).set_bits(start, len, value);
     }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        (**self).set_range(range, value);
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        (**self).toggle_range(range);
+    }
 }
 
 impl<Block: BlockType> BitsMut for [Block] {
@@ -122,6 +268,21 @@ impl<Block: BlockType> BitsMut for [Block] {
     fn set_block(&mut self, position: usize, value: Block) {
         self[position] = value;
     }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        assert!(range.start <= range.end && range.end <= self.bit_len(),
+                "[Block]::set_range: out of bounds");
+
+        let block = if value { !Block::zero() } else { Block::zero() };
+        update_block_range(self, range, |_| block);
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        assert!(range.start <= range.end && range.end <= self.bit_len(),
+                "[Block]::toggle_range: out of bounds");
+
+        update_block_range(self, range, |block| !block);
+    }
 }
 
 impl<Block: BlockType> BitsMut for Vec<Block> {
@@ -132,6 +293,14 @@ impl<Block: BlockType> BitsMut for Vec<Block> {
     fn set_block(&mut self, position: usize, value: Block) {
         <[Block]>::set_block(&mut *self, position, value);
     }
+
+    fn set_range(&mut self, range: Range<u64>, value: bool) {
+        <[Block]>::set_range(&mut *self, range, value);
+    }
+
+    fn toggle_range(&mut self, range: Range<u64>) {
+        <[Block]>::toggle_range(&mut *self, range);
+    }
 }
 
 impl BitsMut for [bool] {
@@ -1,5 +1,10 @@
 use BlockType;
 use super::{Bits, BitsMut};
+use adapter::{BitFill, BitSliceAdapter};
+use {LengthMismatch, LengthPolicy};
+
+use std::cmp;
+use std::ops::Range;
 
 /// Extension trait for mutable operations on bit slices.
 pub trait BitsMutExt: BitsMut {
@@ -54,6 +59,52 @@ pub trait BitsMutExt: BitsMut {
         self.bit_zip_assign(other, |b1, b2| b1 ^ b2);
     }
 
+    /// Assigns the bit-wise *and* of `self` and the complement of
+    /// `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    fn bit_and_not_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) {
+        self.bit_zip_assign(other, |b1, b2| b1 & !b2);
+    }
+
+    /// Assigns the bit-wise *nand* of `self` and `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    fn bit_nand_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) {
+        self.bit_zip_assign(other, |b1, b2| !(b1 & b2));
+    }
+
+    /// Assigns the bit-wise *nor* of `self` and `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    fn bit_nor_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) {
+        self.bit_zip_assign(other, |b1, b2| !(b1 | b2));
+    }
+
+    /// Assigns the bit-wise *xnor* of `self` and `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    fn bit_xnor_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) {
+        self.bit_zip_assign(other, |b1, b2| !(b1 ^ b2));
+    }
+
+    /// Assigns the bit-wise implication from `self` to `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    fn bit_implies_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) {
+        self.bit_zip_assign(other, |b1, b2| !b1 | b2);
+    }
+
     /// Performs an op-assignment from `other` to `self`.
     ///
     /// In particular, the given function is used to combine each
@@ -88,9 +139,466 @@ pub trait BitsMutExt: BitsMut {
             self.set_bits(bit_len - extra_bits as u64, extra_bits, combined_block);
         }
     }
+
+    /// Like [`bit_and_assign`](#method.bit_and_assign), with `policy` deciding what
+    /// happens when the lengths of `self` and `other` differ.
+    ///
+    /// The length of `self` never changes, so under
+    /// [`LengthPolicy::ExtendWith`] a longer `other` is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the lengths differ, in which case
+    /// `self` is unchanged.
+    ///
+    /// [`LengthPolicy::ExtendWith`]: enum.LengthPolicy.html#variant.ExtendWith
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_and_assign_with<T>(&mut self, other: T, policy: LengthPolicy)
+                              -> Result<(), LengthMismatch>
+        where T: Bits<Block = Self::Block> {
+
+        self.bit_zip_assign_with(other, |b1, b2| b1 & b2, policy)
+    }
+
+    /// Like [`bit_or_assign`](#method.bit_or_assign), with `policy` deciding what
+    /// happens when the lengths of `self` and `other` differ.
+    ///
+    /// The length of `self` never changes, so under
+    /// [`LengthPolicy::ExtendWith`] a longer `other` is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the lengths differ, in which case
+    /// `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![false, false, true, false, false];
+    /// let mask: BitVec = bit_vec![true, false];
+    ///
+    /// bv.bit_or_assign_with(&mask, LengthPolicy::Truncate).unwrap();
+    /// assert_eq!( bv, bit_vec![true, false, true, false, false] );
+    ///
+    /// bv.bit_and_assign_with(&mask, LengthPolicy::ExtendWith(true)).unwrap();
+    /// assert_eq!( bv, bit_vec![true, false, true, false, false] );
+    ///
+    /// assert!( bv.bit_xor_assign_with(&mask, LengthPolicy::Strict).is_err() );
+    /// ```
+    ///
+    /// [`LengthPolicy::ExtendWith`]: enum.LengthPolicy.html#variant.ExtendWith
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_or_assign_with<T>(&mut self, other: T, policy: LengthPolicy)
+                             -> Result<(), LengthMismatch>
+        where T: Bits<Block = Self::Block> {
+
+        self.bit_zip_assign_with(other, |b1, b2| b1 | b2, policy)
+    }
+
+    /// Like [`bit_xor_assign`](#method.bit_xor_assign), with `policy` deciding what
+    /// happens when the lengths of `self` and `other` differ.
+    ///
+    /// The length of `self` never changes, so under
+    /// [`LengthPolicy::ExtendWith`] a longer `other` is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the lengths differ, in which case
+    /// `self` is unchanged.
+    ///
+    /// [`LengthPolicy::ExtendWith`]: enum.LengthPolicy.html#variant.ExtendWith
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_xor_assign_with<T>(&mut self, other: T, policy: LengthPolicy)
+                              -> Result<(), LengthMismatch>
+        where T: Bits<Block = Self::Block> {
+
+        self.bit_zip_assign_with(other, |b1, b2| b1 ^ b2, policy)
+    }
+
+    /// Like [`bit_zip_assign`](#method.bit_zip_assign), with `policy`
+    /// deciding what happens when the lengths of `self` and `other` differ.
+    ///
+    /// The length of `self` never changes, so under
+    /// [`LengthPolicy::ExtendWith`] a longer `other` is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Under [`LengthPolicy::Strict`], if the lengths differ, in which case
+    /// `self` is unchanged.
+    ///
+    /// [`LengthPolicy::ExtendWith`]: enum.LengthPolicy.html#variant.ExtendWith
+    /// [`LengthPolicy::Strict`]: enum.LengthPolicy.html#variant.Strict
+    fn bit_zip_assign_with<T, F>(&mut self, other: T, mut fun: F, policy: LengthPolicy)
+                                 -> Result<(), LengthMismatch>
+        where T: Bits<Block = Self::Block>,
+              F: FnMut(Self::Block, Self::Block) -> Self::Block {
+
+        let len1 = self.bit_len();
+        let len2 = other.bit_len();
+        let (_, pad, fill) = policy.padding(len1, len2)?;
+        let common = cmp::min(len1, len2);
+
+        BitSliceAdapter::new(&mut *self, 0, common)
+            .bit_zip_assign(BitSliceAdapter::new(other, 0, common), &mut fun);
+
+        // Only a shorter `other` gets padding.
+        if pad > 0 {
+            BitSliceAdapter::new(&mut *self, common, pad)
+                .bit_zip_assign(BitFill::new(fill, pad), fun);
+        }
+
+        Ok(())
+    }
+
+    /// Adds `other` to `self` in place, treating both as unsigned integers
+    /// with the least-significant bit first, and returns whether the sum
+    /// carried out of the top bit.
+    ///
+    /// The sum wraps around modulo two to `self.bit_len()`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// // 11 + 6 = 17, which wraps around to 1.
+    /// let mut bv: BitVec = bit_vec![true, true, false, true];
+    /// assert!( bv.bit_add_assign(&bit_vec![false, true, true, false]) );
+    /// assert_eq!( bv, bit_vec![true, false, false, false] );
+    /// ```
+    fn bit_add_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) -> bool {
+        assert_eq!( self.bit_len(), other.bit_len(),
+                    "BitsMutExt::bit_add_assign: arguments have different lengths" );
+
+        let bit_len   = self.bit_len();
+        let mut carry = false;
+
+        for i in 0 .. self.block_len() {
+            let count = Self::Block::block_bits(bit_len, i);
+            let a     = self.get_block(i);
+            let sum   = a.wrapping_add(other.get_block(i));
+            let total = sum.wrapping_add(if carry {Self::Block::one()} else {Self::Block::zero()});
+
+            // In a partial block, the masked operands cannot overflow the
+            // block, so the carry lands in bit `count`.
+            carry = if count == Self::Block::nbits() {
+                sum < a || total < sum
+            } else {
+                total.get_bit(count)
+            };
+
+            self.set_bits(Self::Block::mul_nbits(i), count, total);
+        }
+
+        carry
+    }
+
+    /// Subtracts `other` from `self` in place, treating both as unsigned
+    /// integers with the least-significant bit first, and returns whether
+    /// the difference borrowed past the top bit, that is, whether `other`
+    /// was greater.
+    ///
+    /// The difference wraps around modulo two to `self.bit_len()`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.bit_len() != other.bit_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// // 11 - 6 = 5.
+    /// let mut bv: BitVec = bit_vec![true, true, false, true];
+    /// assert!( !bv.bit_sub_assign(&bit_vec![false, true, true, false]) );
+    /// assert_eq!( bv, bit_vec![true, false, true, false] );
+    /// ```
+    fn bit_sub_assign<T: Bits<Block = Self::Block>>(&mut self, other: T) -> bool {
+        assert_eq!( self.bit_len(), other.bit_len(),
+                    "BitsMutExt::bit_sub_assign: arguments have different lengths" );
+
+        let bit_len    = self.bit_len();
+        let mut borrow = false;
+
+        for i in 0 .. self.block_len() {
+            let count = Self::Block::block_bits(bit_len, i);
+            let a     = self.get_block(i);
+            let b     = other.get_block(i);
+            let diff  = a.wrapping_sub(b)
+                         .wrapping_sub(if borrow {Self::Block::one()} else {Self::Block::zero()});
+
+            borrow = a < b || (a == b && borrow);
+
+            self.set_bits(Self::Block::mul_nbits(i), count, diff);
+        }
+
+        borrow
+    }
+
+    /// Adds one to `self` in place, treating it as an unsigned integer with
+    /// the least-significant bit first, and returns whether it wrapped
+    /// around to zero.
+    ///
+    /// This stops at the first block that does not carry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, true, false];
+    /// assert!( !bv.bit_increment() );
+    /// assert_eq!( bv, bit_vec![false, false, true] );
+    /// ```
+    fn bit_increment(&mut self) -> bool {
+        let bit_len = self.bit_len();
+
+        for i in 0 .. self.block_len() {
+            let count = Self::Block::block_bits(bit_len, i);
+            let start = Self::Block::mul_nbits(i);
+            let block = self.get_block(i);
+
+            if block == Self::Block::low_mask(count) {
+                self.set_bits(start, count, Self::Block::zero());
+            } else {
+                self.set_bits(start, count, block.wrapping_add(Self::Block::one()));
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Copies the bits in `src` to the positions starting at `dest`, like
+    /// `slice::copy_within`.
+    ///
+    /// The two ranges may overlap and need not have the same alignment.
+    /// The copy proceeds a destination block at a time, forward or
+    /// backward as needed so that no source bit is overwritten before it
+    /// is read.
+    ///
+    /// # Panics
+    ///
+    /// If `src` is out of bounds, or if there are fewer than
+    /// `src.end - src.start` bits from `dest` to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, true, false, true, false, false];
+    /// bv.bit_copy_within(0 .. 4, 2);
+    /// assert_eq!( bv, bit_vec![true, true, true, true, false, true] );
+    /// ```
+    fn bit_copy_within(&mut self, src: Range<u64>, dest: u64) {
+        let len = self.bit_len();
+        assert!(src.start <= src.end && src.end <= len,
+                "BitsMutExt::bit_copy_within: source out of bounds");
+        let count = src.end - src.start;
+        assert!(dest <= len - count,
+                "BitsMutExt::bit_copy_within: destination out of bounds");
+
+        let nbits = Self::Block::nbits() as u64;
+
+        // Each step reads its source bits before writing its destination
+        // bits, and the steps move away from the source bits not yet read.
+        if dest <= src.start {
+            let mut done = 0;
+            while done < count {
+                let chunk = cmp::min(nbits - Self::Block::mod_nbits(dest + done) as u64,
+                                     count - done);
+                move_bits(self, src.start + done, dest + done, chunk as usize);
+                done += chunk;
+            }
+        } else {
+            let mut left = count;
+            while left > 0 {
+                let tail  = Self::Block::mod_nbits(dest + left) as u64;
+                let chunk = cmp::min(if tail == 0 { nbits } else { tail }, left);
+                left -= chunk;
+                move_bits(self, src.start + left, dest + left, chunk as usize);
+            }
+        }
+    }
+
+    /// Shifts the bits of `self` by `shift` places toward the higher indices,
+    /// filling the vacated low positions with `fill`.
+    ///
+    /// Bits shifted past the end are discarded. Shifts that are a multiple
+    /// of `Block::nbits()` move whole blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, false, true, true];
+    /// bv.shift_left_in_place(1, false);
+    /// assert_eq!( bv, bit_vec![false, true, false, true] );
+    /// ```
+    fn shift_left_in_place(&mut self, shift: u64, fill: bool) {
+        let len   = self.bit_len();
+        let shift = cmp::min(shift, len);
+        if shift == 0 { return; }
+
+        self.bit_copy_within(0 .. len - shift, shift);
+        self.set_range(0 .. shift, fill);
+    }
+
+    /// Shifts the bits of `self` by `shift` places toward the lower indices,
+    /// filling the vacated high positions with `fill`.
+    ///
+    /// Bits shifted past the start are discarded. Shifts that are a
+    /// multiple of `Block::nbits()` move whole blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, false, true, true];
+    /// bv.shift_right_in_place(1, true);
+    /// assert_eq!( bv, bit_vec![false, true, true, true] );
+    /// ```
+    fn shift_right_in_place(&mut self, shift: u64, fill: bool) {
+        let len   = self.bit_len();
+        let shift = cmp::min(shift, len);
+        if shift == 0 { return; }
+
+        self.bit_copy_within(shift .. len, 0);
+        self.set_range(len - shift .. len, fill);
+    }
+
+    /// Rotates the bits of `self` by `shift` places toward the higher
+    /// indices, so that the bits shifted past the end reappear at the start.
+    ///
+    /// Uses temporary storage for the smaller of the two rotated parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, true, false, false, false];
+    /// bv.bit_rotate_left(4);
+    /// assert_eq!( bv, bit_vec![true, false, false, false, true] );
+    /// ```
+    fn bit_rotate_left(&mut self, shift: u64) {
+        let len = self.bit_len();
+        if len == 0 { return; }
+
+        let shift = shift % len;
+        if shift > len / 2 {
+            return self.bit_rotate_right(len - shift);
+        }
+
+        let saved = save_bits(self, len - shift, shift);
+        self.shift_left_in_place(shift, false);
+        restore_bits(self, 0, shift, &saved);
+    }
+
+    /// Rotates the bits of `self` by `shift` places toward the lower
+    /// indices, so that the bits shifted past the start reappear at the end.
+    ///
+    /// Uses temporary storage for the smaller of the two rotated parts.
+    fn bit_rotate_right(&mut self, shift: u64) {
+        let len = self.bit_len();
+        if len == 0 { return; }
+
+        let shift = shift % len;
+        if shift > len / 2 {
+            return self.bit_rotate_left(len - shift);
+        }
+
+        let saved = save_bits(self, 0, shift);
+        self.shift_right_in_place(shift, false);
+        restore_bits(self, len - shift, shift, &saved);
+    }
+
+    /// Reverses the order of the bits of `self`.
+    ///
+    /// Works inward from both ends, swapping and reversing up to a block's
+    /// worth of bits at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true, true, false, true, false];
+    /// bv.reverse_in_place();
+    /// assert_eq!( bv, bit_vec![false, true, false, true, true] );
+    /// ```
+    fn reverse_in_place(&mut self) {
+        let len   = self.bit_len();
+        let half  = len / 2;
+        let nbits = Self::Block::nbits();
+
+        let mut position = 0;
+        while position < half {
+            let count = cmp::min(nbits as u64, half - position) as usize;
+            let other = len - position - count as u64;
+
+            let low   = self.get_bits(position, count);
+            let high  = self.get_bits(other, count);
+            self.set_bits(position, count, high.reverse_bits() >> (nbits - count));
+            self.set_bits(other, count, low.reverse_bits() >> (nbits - count));
+
+            position += count as u64;
+        }
+    }
+}
+
+impl<T: BitsMut + ?Sized> BitsMutExt for T {}
+
+// Copies `count <= Block::nbits()` bits from `src` to `dest`. Whole aligned
+// blocks are moved directly; anything else goes through `get_bits`, which
+// combines the two neighbouring source blocks.
+fn move_bits<T: BitsMut + ?Sized>(bits: &mut T, src: u64, dest: u64, count: usize) {
+    let nbits = T::Block::nbits();
+    if count == nbits && T::Block::mod_nbits(src) == 0 && T::Block::mod_nbits(dest) == 0 {
+        let block = bits.get_raw_block(T::Block::div_nbits(src));
+        bits.set_block(T::Block::div_nbits(dest), block);
+    } else {
+        let block = bits.get_bits(src, count);
+        bits.set_bits(dest, count, block);
+    }
 }
 
-impl<T: BitsMut> BitsMutExt for T {}
+// Copies `count` bits starting at `start` into a vector of blocks.
+fn save_bits<T: Bits + ?Sized>(bits: &T, start: u64, count: u64) -> Vec<T::Block> {
+    let nbits = T::Block::nbits() as u64;
+    let mut result = Vec::with_capacity(T::Block::ceil_div_nbits(count));
+
+    let mut offset = 0;
+    while offset < count {
+        let chunk = cmp::min(nbits, count - offset);
+        result.push(bits.get_bits(start + offset, chunk as usize));
+        offset += chunk;
+    }
+
+    result
+}
+
+// Writes back `count` bits saved by `save_bits`, starting at `start`.
+fn restore_bits<T: BitsMut + ?Sized>(bits: &mut T, start: u64, count: u64,
+                                     saved: &[T::Block]) {
+    let nbits = T::Block::nbits() as u64;
+
+    for (i, &block) in saved.iter().enumerate() {
+        let offset = T::Block::mul_nbits(i);
+        let chunk  = cmp::min(nbits, count - offset);
+        bits.set_bits(start + offset, chunk as usize, block);
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -125,4 +633,135 @@ mod test {
 
         assert_eq!(v1, vec![0b00100111])
     }
+
+    #[test]
+    fn shift_in_place_whole_blocks() {
+        let mut v = [0x11u8, 0x22, 0x33, 0x44];
+        v[..].shift_left_in_place(16, false);
+        assert_eq!( v, [0, 0, 0x11, 0x22] );
+        v.shift_right_in_place(8, true);
+        assert_eq!( v, [0, 0x11, 0x22, 0xFF] );
+    }
+
+    #[test]
+    fn shift_in_place_keeps_trailing_bits() {
+        let mut v = vec![0b1111_0000u8, 0b1010_1010];
+        {
+            let mut slice = v.bit_slice_mut(2..12);
+            slice.shift_left_in_place(3, true);
+        }
+        assert_eq!( v, vec![0b1001_1100, 0b1010_0111] );
+        {
+            let mut slice = v.bit_slice_mut(2..12);
+            slice.shift_right_in_place(3, false);
+        }
+        assert_eq!( v, vec![0b1111_0000, 0b1010_0000] );
+    }
+
+    #[test]
+    fn bit_copy_within_overlapping() {
+        let mut v = [0x0Fu8, 0xF0, 0x00];
+        v.bit_copy_within(4 .. 16, 2);
+        assert_eq!( v, [0x03, 0xFC, 0x00] );
+        v.bit_copy_within(8 .. 16, 14);
+        assert_eq!( v, [0x03, 0x3C, 0x3F] );
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_copy_within_out_of_bounds() {
+        let mut bv: BitVec = bit_vec![false; 10];
+        bv.bit_copy_within(2 .. 8, 5);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut bv: BitVec<u8> = bit_vec![true, false, false, true, true,
+                                          false, true, false, false, false];
+        bv.bit_rotate_left(3);
+        assert_eq!( bv, bit_vec![false, false, false, true, false,
+                                 false, true, true, false, true] );
+        bv.bit_rotate_right(3);
+        assert_eq!( bv, bit_vec![true, false, false, true, true,
+                                 false, true, false, false, false] );
+        bv.bit_rotate_right(28);
+        assert_eq!( bv, bit_vec![false, false, true, false, false,
+                                 true, true, false, true, false] );
+    }
+
+    fn shl_reference(bits: &[bool], shift: usize, fill: bool) -> Vec<bool> {
+        (0 .. bits.len())
+            .map(|i| if i < shift {fill} else {bits[i - shift]})
+            .collect()
+    }
+
+    quickcheck! {
+        fn prop_shift_left(bits: Vec<bool>, start: usize, shift: usize, fill: bool) -> bool {
+            let mut bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let start = if bits.is_empty() {0} else {start % bits.len()};
+            let shift = shift % (bits.len() + 2);
+
+            bv.as_mut_slice().bit_slice(start as u64 ..).shift_left_in_place(shift as u64, fill);
+
+            let mut expected = bits.clone();
+            let shifted = shl_reference(&bits[start ..], shift, fill);
+            expected[start ..].copy_from_slice(&shifted);
+            bv == BitVec::<u8>::from_bits(&expected)
+        }
+
+        fn prop_shift_right(bits: Vec<bool>, shift: usize, fill: bool) -> bool {
+            let mut bv: BitVec<u16> = BitVec::new();
+            for &bit in &bits { bv.push(bit); }
+            let shift = shift % (bits.len() + 2);
+            bv.shift_right_in_place(shift as u64, fill);
+
+            let len = bits.len();
+            (0 .. len).all(|i| {
+                let expected = if i + shift < len {bits[i + shift]} else {fill};
+                bv[i as u64] == expected
+            })
+        }
+
+        fn prop_bit_copy_within(bits: Vec<bool>, start: usize, end: usize, dest: usize) -> bool {
+            let len = bits.len();
+            let (start, end) = if len == 0 {
+                (0, 0)
+            } else {
+                let a = start % (len + 1);
+                let b = end % (len + 1);
+                (a.min(b), a.max(b))
+            };
+            let dest = dest % (len - (end - start) + 1);
+
+            let mut expected = bits.clone();
+            let copied = bits[start .. end].to_vec();
+            expected[dest .. dest + copied.len()].copy_from_slice(&copied);
+
+            let mut bv: BitVec<u8> = BitVec::from_bits(&bits);
+            bv.bit_copy_within(start as u64 .. end as u64, dest as u64);
+            bv == BitVec::<u8>::from_bits(&expected)
+        }
+
+        fn prop_reverse_in_place(bits: Vec<bool>, start: usize) -> bool {
+            let mut bv: BitVec<u8> = BitVec::from_bits(&bits);
+            let start = if bits.is_empty() {0} else {start % bits.len()};
+            bv.as_mut_slice().bit_slice(start as u64 ..).reverse_in_place();
+
+            let mut expected = bits.clone();
+            expected[start ..].reverse();
+            bv == BitVec::<u8>::from_bits(&expected)
+        }
+
+        fn prop_rotate(bits: Vec<bool>, shift: u64) -> bool {
+            let mut bv: BitVec<u8> = BitVec::from_bits(&bits);
+            bv.bit_rotate_left(shift);
+            let len = bits.len() as u64;
+            let rotated_back = (0 .. len).all(|i| {
+                bv[(i + shift % len) % len] == bits[i as usize]
+            });
+
+            bv.bit_rotate_right(shift);
+            rotated_back && bv == BitVec::<u8>::from_bits(&bits)
+        }
+    }
 }
\ No newline at end of file
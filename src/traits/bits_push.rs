@@ -1,4 +1,4 @@
-use super::BitsMut;
+use super::{Bits, BitsMut};
 use storage::BlockType;
 
 /// Bit vector operations that change the length.
@@ -32,6 +32,60 @@ pub trait BitsPush: BitsMut {
             value = value >> 1;
         }
     }
+
+    /// Pushes the `count` least significant bits of `value` onto the end
+    /// of the bit vector, least significant first.
+    ///
+    /// Unlike [`push_block`](#method.push_block), this does not pad to a
+    /// block boundary first.
+    ///
+    /// The default implementation pushes one bit at a time; override it
+    /// with something more efficient.
+    ///
+    /// # Panics
+    ///
+    /// If `count > Self::Block::nbits()`.
+    fn push_bits(&mut self, value: Self::Block, count: usize) {
+        assert!(count <= Self::Block::nbits(), "BitsPush::push_bits: count too large");
+
+        for i in 0 .. count {
+            self.push_bit(value.get_bit(i));
+        }
+    }
+
+    /// Pushes all the bits of `other` onto the end of the bit vector,
+    /// without padding to a block boundary.
+    ///
+    /// The default implementation uses [`push_bits`](#method.push_bits)
+    /// a block at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bv::*;
+    ///
+    /// let mut bv: BitVec = bit_vec![true];
+    /// bv.extend_from_bits(bit_vec![false, true].bit_not());
+    /// bv.push_bits(0b1010u8 as usize, 3);
+    ///
+    /// assert_eq!( bv, bit_vec![true, true, false, false, true, false] );
+    /// ```
+    fn extend_from_bits<T>(&mut self, other: T)
+        where T: Bits<Block = Self::Block>,
+              Self: Sized {
+
+        let len         = other.bit_len();
+        let full_blocks = Self::Block::div_nbits(len);
+        let extra_bits  = Self::Block::mod_nbits(len);
+
+        for i in 0 .. full_blocks {
+            self.push_bits(other.get_raw_block(i), Self::Block::nbits());
+        }
+
+        if extra_bits > 0 {
+            self.push_bits(other.get_raw_block(full_blocks), extra_bits);
+        }
+    }
 }
 
 impl BitsPush for Vec<bool> {
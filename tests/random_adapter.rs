@@ -32,6 +32,10 @@ quickcheck! {
     fn prop_usize(program: Program) -> bool {
         program.evaluate::<usize>().check()
     }
+
+    fn prop_count_ones(program: Program, start: usize, end: usize) -> bool {
+        program.evaluate::<u16>().check_count_ones(start, end)
+    }
 }
 
 // The result of evaluating a random program, on both the reference
@@ -46,6 +50,25 @@ impl<Block: BlockType> ProgramResult<Block> {
     fn check(&self) -> bool {
         self.expected == RefImpl::from_bits(&self.actual)
     }
+
+    // Check that counting the ones in the whole vector and in a range
+    // (chosen from the given seeds) agrees with the reference.
+    fn check_count_ones(&self, start: usize, end: usize) -> bool {
+        let len = self.expected.0.len();
+        let (start, end) = if len == 0 {
+            (0, 0)
+        } else {
+            let a = start % (len + 1);
+            let b = end % (len + 1);
+            (cmp::min(a, b), cmp::max(a, b))
+        };
+
+        let count = |start: usize, end: usize|
+            self.expected.0[start .. end].iter().filter(|&&b| b).count() as u64;
+
+        self.actual.count_ones() == count(0, len)
+            && self.actual.count_ones_in(start as u64 .. end as u64) == count(start, end)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -57,6 +80,9 @@ enum Program {
     Xor(Box<Program>, Box<Program>),
     Concat(Box<Program>, Box<Program>),
     Slice(Box<Program>, usize, usize),
+    Shl(Box<Program>, usize, bool),
+    Shr(Box<Program>, usize, bool),
+    Reverse(Box<Program>),
     Force(Box<Program>),
 }
 
@@ -65,7 +91,7 @@ impl Arbitrary for Program {
         use Program::*;
 
         let recur  = |g: &mut G| Box::new(Program::arbitrary(g));
-        let choice = g.gen_range(1, 71);
+        let choice = g.gen_range(1, 86);
 
         match choice {
             01...30 => Constant(RefImpl::arbitrary(g)),
@@ -85,6 +111,17 @@ impl Arbitrary for Program {
                     Slice(program, 0, len)
                 }
             }
+            61...65 => {
+                let program = recur(g);
+                let shift   = g.gen_range(0, program.len() + 2);
+                Shl(program, shift, g.gen())
+            }
+            66...70 => {
+                let program = recur(g);
+                let shift   = g.gen_range(0, program.len() + 2);
+                Shr(program, shift, g.gen())
+            }
+            71...75 => Reverse(recur(g)),
             _       => Force(Box::new(Program::arbitrary(g))),
         }
     }
@@ -104,6 +141,9 @@ impl Arbitrary for Program {
             Xor(ref p1, ref p2)    => { add(&mut res, p1); add(&mut res, p2); }
             Concat(ref p1, ref p2) => { add(&mut res, p1); add(&mut res, p2); }
             Slice(ref p, _, _)     => add(&mut res, p),
+            Shl(ref p, _, _)       => add(&mut res, p),
+            Shr(ref p, _, _)       => add(&mut res, p),
+            Reverse(ref p)         => add(&mut res, p),
             Force(ref p)           => add(&mut res, p),
         }
 
@@ -123,6 +163,9 @@ impl Program {
             Xor(ref p1, ref p2)     => cmp::min(p1.len(), p2.len()),
             Concat(ref p1, ref p2)  => p1.len() + p2.len(),
             Slice(_, _, len)        => len as usize,
+            Shl(ref p, _, _)        => p.len(),
+            Shr(ref p, _, _)        => p.len(),
+            Reverse(ref p)          => p.len(),
             Force(ref p)            => p.len(),
         }
     }
@@ -188,6 +231,30 @@ impl Program {
                 }
             }
 
+            Shl(ref p, shift, fill) => {
+                let res = p.evaluate();
+                ProgramResult {
+                    expected: res.expected.shl(shift, fill),
+                    actual:   Box::new(res.actual.into_bit_shl_fill(shift as u64, fill)),
+                }
+            }
+
+            Shr(ref p, shift, fill) => {
+                let res = p.evaluate();
+                ProgramResult {
+                    expected: res.expected.shr(shift, fill),
+                    actual:   Box::new(res.actual.into_bit_shr_fill(shift as u64, fill)),
+                }
+            }
+
+            Reverse(ref p) => {
+                let res = p.evaluate();
+                ProgramResult {
+                    expected: res.expected.reverse(),
+                    actual:   Box::new(res.actual.into_bit_reverse()),
+                }
+            }
+
             Force(ref p) => {
                 let res = p.evaluate();
                 ProgramResult {
@@ -256,6 +323,22 @@ impl RefImpl {
         result
     }
 
+    fn shl(&self, shift: usize, fill: bool) -> Self {
+        let len = self.0.len();
+        RefImpl((0 .. len).map(|i| if i < shift {fill} else {self.0[i - shift]}).collect())
+    }
+
+    fn shr(&self, shift: usize, fill: bool) -> Self {
+        let len = self.0.len();
+        RefImpl((0 .. len).map(|i| if i + shift < len {self.0[i + shift]} else {fill}).collect())
+    }
+
+    fn reverse(&self) -> Self {
+        let mut result = self.clone();
+        result.0.reverse();
+        result
+    }
+
     fn slice(&self, start: usize, len: usize) -> Self {
         let mut result = RefImpl(Vec::new());
         for i in start .. (start + len) {
@@ -0,0 +1,33 @@
+// Checks that glob-importing the crate doesn't shadow the methods of
+// standard slices and vectors, which implement `BitsMut`.
+
+extern crate bv;
+
+#[allow(unused_imports)]
+use bv::*;
+
+#[cfg(slice_rotate)]
+#[test]
+fn vec_rotate_rotates_elements() {
+    let mut v = vec![1u8, 2, 3, 4];
+    v.rotate_left(1);
+    assert_eq!( v, vec![2, 3, 4, 1] );
+    v.rotate_right(2);
+    assert_eq!( v, vec![4, 1, 2, 3] );
+
+    let mut a = [1u8, 2, 3, 4];
+    a.rotate_left(1);
+    assert_eq!( a, [2, 3, 4, 1] );
+}
+
+#[cfg(slice_copy_within)]
+#[test]
+fn slice_copy_within_copies_elements() {
+    let mut a = [1u8, 2, 3, 4];
+    a.copy_within(0 .. 2, 2);
+    assert_eq!( a, [1, 2, 1, 2] );
+
+    let mut v = vec![1u8, 2, 3, 4];
+    v.copy_within(1 .. 4, 0);
+    assert_eq!( v, vec![2, 3, 4, 4] );
+}